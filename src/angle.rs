@@ -0,0 +1,290 @@
+use crate::{
+    ops::Angle, Finite, InfiniteError, IntoInner, IsFinite, IsSignedUnit, SignedUnit, ToOrd,
+};
+
+/// An angle measured in radians.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct Radians<F: IsFinite>(Finite<F>);
+
+/// An angle measured in degrees.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct Degrees<F: IsFinite>(Finite<F>);
+
+macro_rules! angle_impls {
+    ($ty: ident, $wrap: ident, $wrap_signed: ident, $pos: literal, $signed: literal) => {
+        impl<F: IsFinite> $ty<F> {
+            /// Creates a new angle.
+            #[inline]
+            pub const fn new(val: Finite<F>) -> Self {
+                Self(val)
+            }
+            /// Gets the inner value of this angle.
+            #[inline]
+            pub const fn get(self) -> Finite<F> {
+                self.0
+            }
+            /// Gets the inner floating point value of this angle.
+            #[inline]
+            pub const fn val(self) -> F {
+                self.0.val()
+            }
+        }
+
+        impl<F: IsFinite> From<Finite<F>> for $ty<F> {
+            #[inline]
+            fn from(val: Finite<F>) -> Self {
+                Self(val)
+            }
+        }
+
+        impl<F: IsFinite + Angle> $ty<F> {
+            #[doc = concat!("Wraps this angle to ", $signed, ".")]
+            #[must_use]
+            pub fn normalize_signed(self) -> Self {
+                unsafe { Self(Finite::unchecked(self.val().$wrap_signed())) }
+            }
+            #[doc = concat!("Wraps this angle to ", $pos, ".")]
+            #[must_use]
+            pub fn normalize_positive(self) -> Self {
+                unsafe { Self(Finite::unchecked(self.val().$wrap())) }
+            }
+            /// Finds the shortest rotation from this angle to `other`,
+            #[doc = concat!("in ", $signed, ".")]
+            ///
+            /// This never overflows, unlike subtracting the two angles.
+            #[must_use]
+            pub fn shortest_difference(self, other: Self) -> Self
+            where
+                F: ::core::ops::Sub<Output = F>,
+            {
+                // both angles are wrapped first, so the difference is less than a full turn.
+                let diff = other.val().$wrap() - self.val().$wrap();
+                unsafe { Self(Finite::unchecked(diff.$wrap_signed())) }
+            }
+        }
+
+        impl<F: IsFinite + ToOrd> PartialEq for $ty<F> {
+            #[inline]
+            fn eq(&self, rhs: &Self) -> bool {
+                self.0 == rhs.0
+            }
+        }
+        impl<F: IsFinite + ToOrd> Eq for $ty<F> {}
+        impl<F: IsFinite + ToOrd> PartialOrd for $ty<F> {
+            #[inline]
+            fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+        impl<F: IsFinite + ToOrd> Ord for $ty<F> {
+            #[inline]
+            fn cmp(&self, rhs: &Self) -> ::core::cmp::Ordering {
+                self.0.cmp(&rhs.0)
+            }
+        }
+
+        impl<F: IsFinite + ::core::ops::Add<Output = F>> ::core::ops::Add for $ty<F> {
+            type Output = Self;
+            #[track_caller]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+        impl<F: IsFinite + ::core::ops::Sub<Output = F>> ::core::ops::Sub for $ty<F> {
+            type Output = Self;
+            #[track_caller]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+        impl<F: IsFinite + ::core::ops::Neg<Output = F>> ::core::ops::Neg for $ty<F> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                // negation never overflows
+                unsafe { Self(Finite::unchecked(-self.val())) }
+            }
+        }
+        impl<F: IsFinite + ::core::ops::Mul<Output = F>, Rhs: IntoInner<F>> ::core::ops::Mul<Rhs>
+            for $ty<F>
+        {
+            type Output = Self;
+            #[track_caller]
+            fn mul(self, rhs: Rhs) -> Self {
+                Self(self.0 * rhs.into_inner())
+            }
+        }
+        impl<F: IsFinite + ::core::ops::Div<Output = F>, Rhs: IntoInner<F>> ::core::ops::Div<Rhs>
+            for $ty<F>
+        {
+            type Output = Self;
+            #[track_caller]
+            fn div(self, rhs: Rhs) -> Self {
+                Self(self.0 / rhs.into_inner())
+            }
+        }
+    };
+}
+
+angle_impls!(
+    Radians,
+    wrap_radians,
+    wrap_radians_signed,
+    "`[0, 2π)`",
+    "`(-π, π]`"
+);
+angle_impls!(
+    Degrees,
+    wrap_degrees,
+    wrap_degrees_signed,
+    "`[0, 360)`",
+    "`(-180, 180]`"
+);
+
+impl<F: IsFinite + Angle> Radians<F> {
+    /// Attempts to convert this angle to degrees.
+    /// # Errors
+    /// If the result is non-finite (caused by angles with an extremely large magnitude).
+    pub fn try_to_degrees(self) -> Result<Degrees<F>, InfiniteError> {
+        Finite::try_new(self.val().to_degrees()).map(Degrees)
+    }
+    /// Converts this angle to degrees.
+    /// # Panics
+    /// If the result is non-finite (caused by angles with an extremely large magnitude).
+    #[track_caller]
+    #[must_use]
+    pub fn to_degrees(self) -> Degrees<F> {
+        Degrees(Finite::new(self.val().to_degrees()))
+    }
+}
+impl<F: IsFinite + Angle> Degrees<F> {
+    /// Converts this angle to radians.
+    #[must_use]
+    pub fn to_radians(self) -> Radians<F> {
+        // converting to radians shrinks the magnitude, so it never overflows.
+        unsafe { Radians(Finite::unchecked(self.val().to_radians())) }
+    }
+}
+impl<F: IsFinite + Angle> From<Degrees<F>> for Radians<F> {
+    #[inline]
+    fn from(val: Degrees<F>) -> Self {
+        val.to_radians()
+    }
+}
+
+impl<F: IsFinite + IsSignedUnit + crate::ops::Trig> Radians<F> {
+    /// Computes the sine of this angle.
+    #[must_use]
+    pub fn sin(self) -> SignedUnit<F> {
+        self.0.sin_unit()
+    }
+    /// Computes the cosine of this angle.
+    #[must_use]
+    pub fn cos(self) -> SignedUnit<F> {
+        self.0.cos_unit()
+    }
+    /// Computes the sine and cosine of this angle simultaneously.
+    #[must_use]
+    pub fn sin_cos(self) -> (SignedUnit<F>, SignedUnit<F>) {
+        self.0.sin_cos_unit()
+    }
+}
+impl<F: IsFinite + IsSignedUnit + Angle> Degrees<F> {
+    /// Computes the sine of this angle, which is exact for multiples of 90°.
+    #[must_use]
+    pub fn sin(self) -> SignedUnit<F> {
+        self.sin_cos().0
+    }
+    /// Computes the cosine of this angle, which is exact for multiples of 90°.
+    #[must_use]
+    pub fn cos(self) -> SignedUnit<F> {
+        self.sin_cos().1
+    }
+    /// Computes the sine and cosine of this angle simultaneously,
+    /// which are exact for multiples of 90°.
+    #[must_use]
+    pub fn sin_cos(self) -> (SignedUnit<F>, SignedUnit<F>) {
+        let (s, c) = self.val().sin_cos_degrees();
+        unsafe { (SignedUnit::unchecked(s), SignedUnit::unchecked(c)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! rad {
+        ($f: expr) => {
+            Radians::new(Finite::new($f))
+        };
+    }
+    macro_rules! deg {
+        ($f: expr) => {
+            Degrees::new(Finite::new($f))
+        };
+    }
+
+    #[test]
+    fn assert_normalize() {
+        use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+        assert_epsilon!(rad!(-FRAC_PI_2).normalize_positive().get(), 1.5 * PI);
+        assert_epsilon!(rad!(TAU + 1.0).normalize_positive().get(), 1.0);
+        assert_eq!(rad!(-f32::EPSILON * 1e-3).normalize_positive(), rad!(0.0));
+        assert!(rad!(-0.0f32).normalize_positive().val().is_sign_positive());
+        assert_eq!(rad!(PI).normalize_signed(), rad!(PI));
+        assert_eq!(rad!(-PI).normalize_signed(), rad!(PI));
+        assert_epsilon!(rad!(1.5 * PI).normalize_signed().get(), -FRAC_PI_2, 1e-6);
+
+        assert_eq!(deg!(-90.0f32).normalize_positive(), deg!(270.0));
+        assert_eq!(deg!(720.0f32).normalize_positive(), deg!(0.0));
+        assert_eq!(deg!(-180.0f32).normalize_signed(), deg!(180.0));
+        assert_eq!(deg!(190.0f32).normalize_signed(), deg!(-170.0));
+    }
+
+    #[test]
+    fn assert_difference() {
+        assert_eq!(deg!(350.0f32).shortest_difference(deg!(10.0)), deg!(20.0));
+        assert_eq!(deg!(10.0f32).shortest_difference(deg!(350.0)), deg!(-20.0));
+        assert_eq!(deg!(0.0f32).shortest_difference(deg!(180.0)), deg!(180.0));
+        // this would overflow if the angles were subtracted directly.
+        let diff = deg!(f32::MAX).shortest_difference(deg!(-f32::MAX));
+        assert!(diff.val().abs() <= 180.0);
+    }
+
+    #[test]
+    fn assert_convert() {
+        use std::f32::consts::PI;
+
+        assert_epsilon!(Radians::from(deg!(180.0f32)).get(), PI);
+        assert_epsilon!(rad!(PI).to_degrees().get(), 180.0, 1e-5);
+        assert_err!(rad!(f32::MAX).try_to_degrees());
+    }
+
+    #[test]
+    fn assert_trig() {
+        use std::f32::consts::FRAC_PI_6;
+
+        assert_eq!(
+            deg!(90.0f32).sin_cos(),
+            (SignedUnit::new(1.0), SignedUnit::new(0.0))
+        );
+        assert_eq!(deg!(180.0f32).sin(), SignedUnit::new(0.0));
+        assert_eq!(deg!(-90.0f32).sin(), SignedUnit::new(-1.0));
+        assert_eq!(deg!(450.0f32).cos(), SignedUnit::new(0.0));
+        assert_eq!(deg!(-540.0f32).cos(), SignedUnit::new(-1.0));
+        assert_epsilon!(deg!(30.0f32).sin(), SignedUnit::new(0.5));
+        assert_epsilon!(rad!(FRAC_PI_6).sin(), SignedUnit::new(0.5));
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(deg!(90.0f32) + deg!(45.0), deg!(135.0));
+        assert_eq!(deg!(90.0f32) - deg!(135.0), deg!(-45.0));
+        assert_eq!(-deg!(90.0f32), deg!(-90.0));
+        assert_eq!(deg!(90.0f32) * 2.0, deg!(180.0));
+        assert_eq!(deg!(90.0f32) / 2.0, deg!(45.0));
+        assert!(deg!(90.0f32) < deg!(180.0));
+    }
+}
@@ -0,0 +1,390 @@
+use std::{
+    convert::Infallible,
+    fmt,
+    marker::PhantomData,
+    sync::atomic::{AtomicU32, AtomicU64, Ordering},
+};
+
+use crate::{
+    constraint::{self, Constraint},
+    Checked, IntoInner, ToOrd,
+};
+
+/// A floating point number that can be stored in an atomic integer.
+#[doc(hidden)]
+pub trait AtomicFloat: ToOrd {
+    type Atomic: Send + Sync;
+    fn to_atomic(self) -> Self::Atomic;
+    fn from_atomic(atomic: Self::Atomic) -> Self;
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+    fn store(atomic: &Self::Atomic, val: Self, order: Ordering);
+    fn swap(atomic: &Self::Atomic, val: Self, order: Ordering) -> Self;
+    fn compare_exchange(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+    fn compare_exchange_weak(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+}
+
+macro_rules! impl_atomic_float {
+    ($f: ty, $atomic: ty) => {
+        impl AtomicFloat for $f {
+            type Atomic = $atomic;
+            #[inline]
+            fn to_atomic(self) -> $atomic {
+                <$atomic>::new(self.to_bits())
+            }
+            #[inline]
+            fn from_atomic(atomic: $atomic) -> $f {
+                <$f>::from_bits(atomic.into_inner())
+            }
+            #[inline]
+            fn load(atomic: &$atomic, order: Ordering) -> $f {
+                <$f>::from_bits(atomic.load(order))
+            }
+            #[inline]
+            fn store(atomic: &$atomic, val: $f, order: Ordering) {
+                atomic.store(val.to_bits(), order);
+            }
+            #[inline]
+            fn swap(atomic: &$atomic, val: $f, order: Ordering) -> $f {
+                <$f>::from_bits(atomic.swap(val.to_bits(), order))
+            }
+            #[inline]
+            fn compare_exchange(
+                atomic: &$atomic,
+                current: $f,
+                new: $f,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$f, $f> {
+                atomic
+                    .compare_exchange(current.to_bits(), new.to_bits(), success, failure)
+                    .map(<$f>::from_bits)
+                    .map_err(<$f>::from_bits)
+            }
+            #[inline]
+            fn compare_exchange_weak(
+                atomic: &$atomic,
+                current: $f,
+                new: $f,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$f, $f> {
+                atomic
+                    .compare_exchange_weak(current.to_bits(), new.to_bits(), success, failure)
+                    .map(<$f>::from_bits)
+                    .map_err(<$f>::from_bits)
+            }
+        }
+    };
+}
+impl_atomic_float!(f32, AtomicU32);
+impl_atomic_float!(f64, AtomicU64);
+
+/// A checked float which can be safely shared between threads.
+///
+/// The value is stored as its bit pattern in an atomic integer, so it is only
+/// available for `f32` and `f64`. Read-modify-write operations such as
+/// [`try_fetch_add`](AtomicChecked::try_fetch_add) are implemented with compare-and-swap loops,
+/// and never store a value that does not uphold the constraint `C`.
+/// This is usually used through one of its aliases, such as [`AtomicReal`].
+#[repr(transparent)]
+pub struct AtomicChecked<F: AtomicFloat, C: Constraint<F>>(F::Atomic, PhantomData<C>);
+
+/// A [`Real`](crate::Real) which can be safely shared between threads.
+pub type AtomicReal<F> = AtomicChecked<F, constraint::RealC>;
+/// A [`Finite`](crate::Finite) which can be safely shared between threads.
+pub type AtomicFinite<F> = AtomicChecked<F, constraint::FiniteC>;
+/// A [`NonNeg`](crate::NonNeg) which can be safely shared between threads.
+pub type AtomicNonNeg<F> = AtomicChecked<F, constraint::NonNegC>;
+
+/// Finds the strongest ordering that's allowed for the failure case of a compare-and-swap.
+fn failure_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
+
+impl<F: AtomicFloat, C: Constraint<F>> AtomicChecked<F, C> {
+    /// Creates a new atomic float.
+    #[inline]
+    pub fn new(val: Checked<F, C>) -> Self {
+        Self(val.val().to_atomic(), PhantomData)
+    }
+    /// Consumes the atomic and returns the contained value.
+    #[inline]
+    pub fn into_inner(self) -> Checked<F, C> {
+        // SAFETY: every value stored in the atomic has already been checked.
+        unsafe { Checked::unchecked(F::from_atomic(self.0)) }
+    }
+    /// Loads the value.
+    #[inline]
+    pub fn load(&self, order: Ordering) -> Checked<F, C> {
+        // SAFETY: every value stored in the atomic has already been checked.
+        unsafe { Checked::unchecked(F::load(&self.0, order)) }
+    }
+    /// Stores a value.
+    #[inline]
+    pub fn store(&self, val: Checked<F, C>, order: Ordering) {
+        F::store(&self.0, val.val(), order);
+    }
+    /// Stores a value, returning the previous value.
+    #[inline]
+    pub fn swap(&self, val: Checked<F, C>, order: Ordering) -> Checked<F, C> {
+        // SAFETY: the previous value was checked when it was stored.
+        unsafe { Checked::unchecked(F::swap(&self.0, val.val(), order)) }
+    }
+    /// Stores `new` if the current value is `current`, returning the previous value.
+    ///
+    /// Note that the values are compared by their bit patterns, so `-0.0` does not match `+0.0`.
+    /// # Errors
+    /// If the current value was not `current`. The error contains the actual value.
+    #[inline]
+    pub fn compare_exchange(
+        &self,
+        current: Checked<F, C>,
+        new: Checked<F, C>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Checked<F, C>, Checked<F, C>> {
+        // SAFETY: both the previous and the actual value were checked when they were stored.
+        F::compare_exchange(&self.0, current.val(), new.val(), success, failure)
+            .map(|val| unsafe { Checked::unchecked(val) })
+            .map_err(|val| unsafe { Checked::unchecked(val) })
+    }
+    /// Stores `new` if the current value is `current`, returning the previous value.
+    /// Unlike [`compare_exchange`](Self::compare_exchange), this is allowed to spuriously fail,
+    /// which may be more efficient in a loop.
+    ///
+    /// Note that the values are compared by their bit patterns, so `-0.0` does not match `+0.0`.
+    /// # Errors
+    /// If the current value was not `current`, or if it spuriously failed.
+    /// The error contains the actual value.
+    #[inline]
+    pub fn compare_exchange_weak(
+        &self,
+        current: Checked<F, C>,
+        new: Checked<F, C>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Checked<F, C>, Checked<F, C>> {
+        // SAFETY: both the previous and the actual value were checked when they were stored.
+        F::compare_exchange_weak(&self.0, current.val(), new.val(), success, failure)
+            .map(|val| unsafe { Checked::unchecked(val) })
+            .map_err(|val| unsafe { Checked::unchecked(val) })
+    }
+
+    /// Repeatedly applies `f` to the current value until it is stored successfully,
+    /// returning the previous value. If `f` returns `None`, nothing is stored.
+    fn fetch_update<E>(
+        &self,
+        order: Ordering,
+        mut f: impl FnMut(F) -> Result<Option<F>, E>,
+    ) -> Result<F, E> {
+        let failure = failure_ordering(order);
+        let mut prev = F::load(&self.0, failure);
+        loop {
+            let Some(next) = f(prev)? else {
+                return Ok(prev);
+            };
+            match F::compare_exchange_weak(&self.0, prev, next, order, failure) {
+                Ok(prev) => return Ok(prev),
+                Err(actual) => prev = actual,
+            }
+        }
+    }
+
+    /// Stores the maximum of the current value and `val`, returning the previous value.
+    pub fn fetch_max(&self, val: Checked<F, C>, order: Ordering) -> Checked<F, C> {
+        let val = val.val();
+        let prev = self.fetch_update(order, |prev| {
+            Ok::<_, Infallible>((val.to_ord() > prev.to_ord()).then_some(val))
+        });
+        let prev = prev.unwrap_or_else(|never| match never {});
+        // SAFETY: `fetch_update` returns a value that was stored in the atomic, so it has been checked.
+        unsafe { Checked::unchecked(prev) }
+    }
+    /// Stores the minimum of the current value and `val`, returning the previous value.
+    pub fn fetch_min(&self, val: Checked<F, C>, order: Ordering) -> Checked<F, C> {
+        let val = val.val();
+        let prev = self.fetch_update(order, |prev| {
+            Ok::<_, Infallible>((val.to_ord() < prev.to_ord()).then_some(val))
+        });
+        let prev = prev.unwrap_or_else(|never| match never {});
+        // SAFETY: `fetch_update` returns a value that was stored in the atomic, so it has been checked.
+        unsafe { Checked::unchecked(prev) }
+    }
+}
+
+impl<F: AtomicFloat + ::core::ops::Add<Output = F>, C: Constraint<F>> AtomicChecked<F, C> {
+    /// Attempts to add `val` to the current value, returning the previous value.
+    /// # Errors
+    /// If the sum does not uphold the constraint `C`, in which case nothing is stored.
+    pub fn try_fetch_add(
+        &self,
+        val: impl IntoInner<F>,
+        order: Ordering,
+    ) -> Result<Checked<F, C>, C::Error> {
+        let val = val.into_inner();
+        let prev = self.fetch_update(order, |prev| {
+            Checked::<F, C>::try_new(prev + val).map(|next| Some(next.val()))
+        })?;
+        // SAFETY: `fetch_update` returns a value that was stored in the atomic, so it has been checked.
+        Ok(unsafe { Checked::unchecked(prev) })
+    }
+    /// Adds `val` to the current value, returning the previous value.
+    /// # Panics
+    /// If the sum does not uphold the constraint `C`, in which case nothing is stored.
+    /// Unlike most operations, this panics even in release mode.
+    #[track_caller]
+    pub fn fetch_add(&self, val: impl IntoInner<F>, order: Ordering) -> Checked<F, C> {
+        crate::unwrap_display(self.try_fetch_add(val, order))
+    }
+}
+impl<F: AtomicFloat + ::core::ops::Sub<Output = F>, C: Constraint<F>> AtomicChecked<F, C> {
+    /// Attempts to subtract `val` from the current value, returning the previous value.
+    /// # Errors
+    /// If the difference does not uphold the constraint `C`, in which case nothing is stored.
+    pub fn try_fetch_sub(
+        &self,
+        val: impl IntoInner<F>,
+        order: Ordering,
+    ) -> Result<Checked<F, C>, C::Error> {
+        let val = val.into_inner();
+        let prev = self.fetch_update(order, |prev| {
+            Checked::<F, C>::try_new(prev - val).map(|next| Some(next.val()))
+        })?;
+        // SAFETY: `fetch_update` returns a value that was stored in the atomic, so it has been checked.
+        Ok(unsafe { Checked::unchecked(prev) })
+    }
+    /// Subtracts `val` from the current value, returning the previous value.
+    /// # Panics
+    /// If the difference does not uphold the constraint `C`, in which case nothing is stored.
+    /// Unlike most operations, this panics even in release mode.
+    #[track_caller]
+    pub fn fetch_sub(&self, val: impl IntoInner<F>, order: Ordering) -> Checked<F, C> {
+        crate::unwrap_display(self.try_fetch_sub(val, order))
+    }
+}
+
+impl<F: AtomicFloat, C: Constraint<F>> From<Checked<F, C>> for AtomicChecked<F, C> {
+    #[inline]
+    fn from(val: Checked<F, C>) -> Self {
+        Self::new(val)
+    }
+}
+
+impl<F: AtomicFloat + fmt::Debug, C: Constraint<F>> fmt::Debug for AtomicChecked<F, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.load(Ordering::Relaxed).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NegativeError, NonNeg, Real};
+
+    macro_rules! atomic {
+        ($f: expr) => {
+            AtomicNonNeg::new(NonNeg::new($f))
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered a negative or NaN unexpectedly")]
+    fn assert_fetch_sub_neg() {
+        atomic!(1.0f64).fetch_sub(2.0, Ordering::Relaxed);
+    }
+
+    #[test]
+    fn assert_ops() {
+        let a = atomic!(1.0f32);
+        assert_eq!(a.load(Ordering::Relaxed), NonNeg::new(1.0));
+        a.store(NonNeg::new(2.0), Ordering::Relaxed);
+        assert_eq!(
+            a.swap(NonNeg::new(3.0), Ordering::Relaxed),
+            NonNeg::new(2.0)
+        );
+        assert_eq!(
+            a.compare_exchange(
+                NonNeg::new(3.0),
+                NonNeg::new(4.0),
+                Ordering::Relaxed,
+                Ordering::Relaxed
+            ),
+            Ok(NonNeg::new(3.0))
+        );
+        assert_eq!(
+            a.compare_exchange(
+                NonNeg::new(3.0),
+                NonNeg::new(5.0),
+                Ordering::Relaxed,
+                Ordering::Relaxed
+            ),
+            Err(NonNeg::new(4.0))
+        );
+        assert_eq!(format!("{a:?}"), "NonNeg(4.0)");
+        assert_eq!(a.into_inner(), NonNeg::new(4.0));
+    }
+
+    #[test]
+    fn assert_fetch() {
+        let a = atomic!(1.0f64);
+        assert_eq!(a.fetch_add(2.0, Ordering::Relaxed), NonNeg::new(1.0));
+        assert_eq!(a.fetch_sub(0.5, Ordering::Relaxed), NonNeg::new(3.0));
+        // an invalid result is refused, and the old value is kept.
+        assert_eq!(a.try_fetch_sub(3.0, Ordering::Relaxed), Err(NegativeError));
+        assert_eq!(a.load(Ordering::Relaxed), NonNeg::new(2.5));
+
+        assert_eq!(
+            a.fetch_max(NonNeg::new(1.0), Ordering::Relaxed),
+            NonNeg::new(2.5)
+        );
+        assert_eq!(
+            a.fetch_max(NonNeg::new(5.0), Ordering::Relaxed),
+            NonNeg::new(2.5)
+        );
+        assert_eq!(
+            a.fetch_min(NonNeg::new(4.0), Ordering::Relaxed),
+            NonNeg::new(5.0)
+        );
+        assert_eq!(a.load(Ordering::Relaxed), NonNeg::new(4.0));
+
+        let r = AtomicReal::new(Real::new(-1.0f32));
+        r.fetch_max(Real::new(f32::NEG_INFINITY), Ordering::Relaxed);
+        r.fetch_min(Real::new(-2.0), Ordering::Relaxed);
+        assert_eq!(r.load(Ordering::Relaxed), Real::new(-2.0));
+    }
+
+    #[test]
+    fn assert_threads() {
+        let sum = AtomicFinite::new(crate::Finite::new(0.0f64));
+        let max = AtomicReal::new(Real::new(f64::NEG_INFINITY));
+        std::thread::scope(|s| {
+            for i in 0..8 {
+                let (sum, max) = (&sum, &max);
+                s.spawn(move || {
+                    for j in 0..100 {
+                        sum.fetch_add(1.0, Ordering::Relaxed);
+                        max.fetch_max(Real::new(f64::from(i * 100 + j)), Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+        assert_eq!(sum.into_inner(), crate::Finite::new(800.0));
+        assert_eq!(max.into_inner(), Real::new(799.0));
+    }
+}
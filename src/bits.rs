@@ -1,4 +1,4 @@
-use std::cmp;
+use std::{cmp, fmt, hash};
 
 /// A trait that converts a floating point number into something implementing total ordering.
 ///
@@ -24,6 +24,19 @@ pub trait ToOrd: Sized + Copy {
     }
 }
 
+/// A trait that maps a floating point number to an integer key, according to the
+/// IEEE 754 `totalOrder` predicate. Unlike [`ToOrd`], this is defined for NaN.
+#[doc(hidden)]
+pub trait TotalOrdKey: Sized + Copy {
+    type Key: cmp::Ord + hash::Hash;
+    /// `-NaN < -inf < ... < -0.0 < +0.0 < ... < +inf < +NaN`,
+    /// where NaNs are ordered by their sign and payload.
+    fn total_key(self) -> Self::Key;
+    /// Like [`total_key`](TotalOrdKey::total_key), except that every NaN is greater
+    /// than every other number and equal to each other, and `-0.0 == +0.0`.
+    fn collapsed_key(self) -> Self::Key;
+}
+
 macro_rules! impl_to_ord {
     ($f: ty, $u: ty) => {
         impl ToOrd for $f {
@@ -31,30 +44,24 @@ macro_rules! impl_to_ord {
             #[inline]
             fn to_ord(self) -> Self::Ord {
                 const MSB: $u = 1 << (std::mem::size_of::<$f>() * 8 - 1);
-                let bits = self.to_bits();
 
-                if bits & MSB == 0 {
-                    // if it's positive, flip the most significant bit.
-                    bits | MSB
-                } else {
-                    // Special case: if it's negative zero, pretend that it's postive zero.
-                    // This ensures that -0.0 == +0.0
-                    if bits << 1 == 0 {
-                        // Bencharking shows that marking as cold provides a slight performance boost,
-                        // probably because it aids branch prediction.
-                        // This would be even better we if we had an `unlikely` intrinsic, as the special
-                        // case could remain inline.
-                        #[cold]
-                        fn zero() -> $u {
-                            MSB // this is the result of flipping the most significant bit of +0.0
-                        }
-
-                        zero()
-                    }
-                    // If it's any other negative number, flip every bit.
-                    else {
-                        !bits
+                // Special case: if it's negative zero, pretend that it's postive zero.
+                // This ensures that -0.0 == +0.0
+                if self.to_bits() == MSB {
+                    // Bencharking shows that marking as cold provides a slight performance boost,
+                    // probably because it aids branch prediction.
+                    // This would be even better we if we had an `unlikely` intrinsic, as the special
+                    // case could remain inline.
+                    #[cold]
+                    fn zero() -> $u {
+                        MSB // this is the result of flipping the most significant bit of +0.0
                     }
+
+                    zero()
+                }
+                // Any other number is ordered the same as IEEE 754 `totalOrder`.
+                else {
+                    TotalOrdKey::total_key(self)
                 }
             }
             #[inline]
@@ -77,7 +84,152 @@ macro_rules! impl_to_ord {
                 }
             }
         }
+        impl TotalOrdKey for $f {
+            type Key = $u;
+            #[inline]
+            fn total_key(self) -> $u {
+                const MSB: $u = 1 << (std::mem::size_of::<$f>() * 8 - 1);
+                let bits = self.to_bits();
+
+                if bits & MSB == 0 {
+                    // if it's positive, flip the most significant bit.
+                    bits | MSB
+                } else {
+                    // if it's negative, flip every bit.
+                    !bits
+                }
+            }
+            #[inline]
+            fn collapsed_key(self) -> $u {
+                if self.is_nan() {
+                    <$u>::MAX
+                } else {
+                    self.to_ord()
+                }
+            }
+        }
     };
 }
 impl_to_ord!(f32, u32);
 impl_to_ord!(f64, u64);
+
+macro_rules! total_ord_impls {
+    ($ty: ident, $key: ident) => {
+        impl<F: TotalOrdKey> PartialEq for $ty<F> {
+            #[inline]
+            fn eq(&self, rhs: &Self) -> bool {
+                self.0.$key() == rhs.0.$key()
+            }
+        }
+        impl<F: TotalOrdKey> Eq for $ty<F> {}
+        impl<F: TotalOrdKey> PartialOrd for $ty<F> {
+            #[inline]
+            fn partial_cmp(&self, rhs: &Self) -> Option<cmp::Ordering> {
+                Some(self.cmp(rhs))
+            }
+        }
+        impl<F: TotalOrdKey> Ord for $ty<F> {
+            #[inline]
+            fn cmp(&self, rhs: &Self) -> cmp::Ordering {
+                self.0.$key().cmp(&rhs.0.$key())
+            }
+        }
+        impl<F: TotalOrdKey> hash::Hash for $ty<F> {
+            #[inline]
+            fn hash<H: hash::Hasher>(&self, state: &mut H) {
+                self.0.$key().hash(state);
+            }
+        }
+        impl<F: fmt::Debug> fmt::Debug for $ty<F> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+        impl<F> From<F> for $ty<F> {
+            #[inline]
+            fn from(val: F) -> Self {
+                Self(val)
+            }
+        }
+    };
+}
+
+/// A raw floating point number, which implements total ordering and hashing according to
+/// the IEEE 754 `totalOrder` predicate, even if it is NaN.
+///
+/// Every bit pattern is distinct: `-0.0 < +0.0`, negative NaNs are less than every other number,
+/// and positive NaNs are greater than every other number. Use [`CollapsedOrd`] to treat
+/// every NaN as equal, and `-0.0` as equal to `+0.0`.
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct TotalOrd<F>(pub F);
+total_ord_impls!(TotalOrd, total_key);
+
+/// A raw floating point number, which implements total ordering and hashing even if it is NaN.
+///
+/// Unlike [`TotalOrd`], every NaN is equal to each other and greater than every other number,
+/// and `-0.0 == +0.0`. This agrees with the ordering of [`Real`](crate::Real).
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct CollapsedOrd<F>(pub F);
+total_ord_impls!(CollapsedOrd, collapsed_key);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn assert_total_ord() {
+        let neg_nan = -f32::NAN.copysign(1.0);
+        let mut vals = [
+            f32::NAN,
+            1.0,
+            0.0,
+            f32::INFINITY,
+            neg_nan,
+            -0.0,
+            f32::NEG_INFINITY,
+        ]
+        .map(TotalOrd);
+        vals.sort();
+        let bits = vals.map(|x| x.0.to_bits());
+        let expected = [
+            neg_nan,
+            f32::NEG_INFINITY,
+            -0.0,
+            0.0,
+            1.0,
+            f32::INFINITY,
+            f32::NAN,
+        ];
+        assert_eq!(bits, expected.map(f32::to_bits));
+
+        assert!(TotalOrd(-0.0f64) < TotalOrd(0.0));
+        assert_eq!(TotalOrd(f64::NAN), TotalOrd(f64::NAN));
+        // NaNs with different payloads are distinct.
+        let other_nan = f64::from_bits(f64::NAN.to_bits() + 1);
+        assert!(TotalOrd(f64::NAN) < TotalOrd(other_nan));
+    }
+
+    #[test]
+    fn assert_collapsed_ord() {
+        let neg_nan = -f32::NAN.copysign(1.0);
+        assert_eq!(CollapsedOrd(neg_nan), CollapsedOrd(f32::NAN));
+        assert!(CollapsedOrd(neg_nan) > CollapsedOrd(f32::INFINITY));
+        assert_eq!(CollapsedOrd(-0.0f32), CollapsedOrd(0.0));
+        assert!(CollapsedOrd(-1.0f32) < CollapsedOrd(0.0));
+
+        // dedupe raw data that contains NaN.
+        let set: HashSet<_> = [1.0f64, f64::NAN, -f64::NAN, 0.0, -0.0, 1.0]
+            .into_iter()
+            .map(CollapsedOrd)
+            .collect();
+        assert_eq!(set.len(), 3);
+        let set: HashSet<_> = [f64::NAN, -f64::NAN, 0.0, -0.0]
+            .into_iter()
+            .map(TotalOrd)
+            .collect();
+        assert_eq!(set.len(), 4);
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+
+use crate::{constraint::BoundedC, Checked, Finite, IsFinite, IsNan, Real, ToOrd};
+
+/// The error produced when a value outside of the bounds of a [`Bounded`] float, or NaN, is encountered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundsError<F> {
+    /// The lower bound of the float that was being checked.
+    pub min: F,
+    /// The upper bound of the float that was being checked.
+    pub max: F,
+}
+impl<F: fmt::Display> fmt::Display for BoundsError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "encountered a value outside of [{}, {}] or NaN unexpectedly",
+            self.min, self.max
+        )
+    }
+}
+
+/// Trait for a marker type that provides the (inclusive) bounds of a [`Bounded`] float.
+///
+/// Ensure that `MIN <= MAX`, and that both bounds are finite.
+pub trait Bounds<F> {
+    /// The smallest value allowed.
+    const MIN: F;
+    /// The largest value allowed.
+    const MAX: F;
+}
+
+/// A floating point number within the inclusive range `[B::MIN, B::MAX]`.
+pub type Bounded<F, B> = Checked<F, BoundedC<B>>;
+
+impl<F, B> Bounded<F, B>
+where
+    F: IsNan + ToOrd + fmt::Debug + fmt::Display,
+    B: Bounds<F>,
+{
+    /// The smallest value of this type.
+    pub const MIN: Self = unsafe { Self::unchecked(B::MIN) };
+    /// The largest value of this type.
+    pub const MAX: Self = unsafe { Self::unchecked(B::MAX) };
+
+    /// Creates a new `Bounded` float, clamping the value to be within the bounds.
+    /// # Panics
+    /// If the value is NaN.
+    /// Note that this fn will *not* panic in release mode, unless the `strict` feature flag is set.
+    #[track_caller]
+    pub fn clamp_new(val: F) -> Self {
+        if val.is_nan() {
+            Self::new(val)
+        } else if val.to_ord() < B::MIN.to_ord() {
+            Self::MIN
+        } else if val.to_ord() > B::MAX.to_ord() {
+            Self::MAX
+        } else {
+            unsafe { Self::unchecked(val) }
+        }
+    }
+}
+
+// the bounds are finite, so every `Bounded` is also `Finite`.
+convert_impls!(
+    @impl [B: Bounds<F>, F: IsNan + ToOrd + fmt::Debug + fmt::Display]
+    Bounded<F, B>, BoundsError<F> => Finite<F: IsFinite>, Real<F: IsNan>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    enum Percent {}
+    impl Bounds<f32> for Percent {
+        const MIN: f32 = 0.0;
+        const MAX: f32 = 100.0;
+    }
+
+    type Pct = Bounded<f32, Percent>;
+
+    macro_rules! pct {
+        ($f: expr) => {
+            Pct::new($f)
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered a value outside of [0, 100] or NaN unexpectedly")]
+    fn assert_new_nan() {
+        pct!(f32::NAN);
+    }
+    #[test]
+    #[should_panic(expected = "encountered a value outside of [0, 100] or NaN unexpectedly")]
+    fn assert_new_big() {
+        pct!(100.5);
+    }
+    #[test]
+    #[should_panic(expected = "encountered a value outside of [0, 100] or NaN unexpectedly")]
+    fn assert_new_small() {
+        pct!(-0.5);
+    }
+    #[test]
+    #[should_panic(expected = "encountered a value outside of [0, 100] or NaN unexpectedly")]
+    fn assert_clamp_nan() {
+        Pct::clamp_new(f32::NAN);
+    }
+
+    #[test]
+    fn assert_bounds() {
+        // both bounds are inclusive, and `-0.0` compares equal to `0.0`.
+        assert_eq!(pct!(0.0), Pct::MIN);
+        assert_eq!(pct!(-0.0), Pct::MIN);
+        assert_eq!(pct!(100.0), Pct::MAX);
+        assert_eq!(
+            Pct::try_new(101.0).unwrap_err(),
+            BoundsError {
+                min: 0.0,
+                max: 100.0
+            }
+        );
+    }
+
+    #[test]
+    fn assert_convert() {
+        assert_eq!(Finite::from(pct!(42.0)), Finite::new(42.0));
+        assert_eq!(Real::from(pct!(100.0)), Real::new(100.0));
+        assert_eq!(Pct::try_from(Finite::new(42.0)), Ok(pct!(42.0)));
+        assert_eq!(
+            Pct::try_from(Real::new(f32::INFINITY)).unwrap_err(),
+            BoundsError {
+                min: 0.0,
+                max: 100.0
+            }
+        );
+    }
+
+    #[test]
+    fn assert_clamp() {
+        let clamp = Pct::clamp_new;
+        assert_eq!(clamp(-10.0), pct!(0.0));
+        assert_eq!(clamp(f32::INFINITY), pct!(100.0));
+        assert_eq!(clamp(42.0), pct!(42.0));
+    }
+
+    #[test]
+    fn assert_nan() {
+        assert_err!(pct!(60.0).try_add(50.0));
+        assert_err!(pct!(10.0).try_sub(20.0));
+        assert_err!(pct!(60.0).try_mul(2.0));
+        assert_err!(pct!(60.0).try_neg());
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(pct!(60.0) + 40.0, pct!(100.0));
+        assert_eq!(pct!(60.0) - pct!(60.0), pct!(0.0));
+        assert_eq!(pct!(25.0) * 4.0, pct!(100.0));
+        assert_eq!(pct!(81.0).sqrt(), pct!(9.0));
+        assert!(pct!(10.0) < pct!(20.0));
+        assert_eq!(pct!(10.0).max(20.0), pct!(20.0));
+    }
+}
@@ -0,0 +1,467 @@
+use std::{fmt, marker::PhantomData};
+
+use crate::{
+    constraint::{CheckedOps, Constraint},
+    IntoInner, ToOrd,
+};
+
+/// A floating point number that is checked at runtime to uphold the constraint `C`.
+///
+/// This is usually used through one of its aliases, such as [`Real`](crate::Real) or
+/// [`Finite`](crate::Finite). See the [`constraint`](crate::constraint) module for
+/// how to combine constraints, or define your own.
+///
+/// The arithmetic operators accept any right-hand side that converts into `F`, and return `Self`.
+/// Coherence rules therefore forbid operator impls for specific pairs of checked types, so an
+/// operation whose operand types guarantee a more precise result is a named method instead,
+/// such as [`Finite::add_real`](crate::Finite::add_real) or
+/// [`NonNeg::add_non_neg`](crate::NonNeg::add_non_neg). These skip the checks that the operand
+/// types make unnecessary, and return the narrowest type that can hold the result.
+#[repr(transparent)]
+pub struct Checked<F, C: Constraint<F>>(F, PhantomData<C>);
+
+impl<F: Copy, C: Constraint<F>> Checked<F, C> {
+    /// Attempts to create a new checked float.
+    /// # Errors
+    /// If the value does not uphold the constraint `C`.
+    pub fn try_new(val: F) -> Result<Self, C::Error> {
+        C::check(val)?;
+        Ok(Self(val, PhantomData))
+    }
+    /// Creates a new checked float.
+    /// # Panics
+    /// If the value does not uphold the constraint `C`.
+    /// Note that this fn will *not* panic in release mode, unless the `strict` feature flag is set.
+    #[track_caller]
+    pub fn new(val: F) -> Self {
+        if crate::STRICT {
+            crate::unwrap_display(Self::try_new(val))
+        } else {
+            // SAFETY: lol
+            unsafe { Self::unchecked(val) }
+        }
+    }
+    /// Const-safe constructor that never checks the value.
+    /// # Safety
+    /// Ensure that the value always upholds the constraint `C`.
+    pub const unsafe fn unchecked(val: F) -> Self {
+        Self(val, PhantomData)
+    }
+    /// Gets the inner value of this number.
+    #[inline]
+    pub const fn val(self) -> F {
+        self.0
+    }
+}
+
+impl<F: Copy, C: Constraint<F>> IntoInner<F> for Checked<F, C> {
+    #[inline]
+    fn into_inner(self) -> F {
+        self.val()
+    }
+}
+
+impl<F: Copy, C: Constraint<F>> Clone for Checked<F, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<F: Copy, C: Constraint<F>> Copy for Checked<F, C> {}
+
+impl<F: fmt::Debug, C: Constraint<F>> fmt::Debug for Checked<F, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        C::fmt_name(f)?;
+        write!(f, "({:?})", self.0)
+    }
+}
+
+impl<F: ToOrd, C: Constraint<F>, Rhs: IntoInner<F> + Copy> PartialEq<Rhs> for Checked<F, C> {
+    fn eq(&self, rhs: &Rhs) -> bool {
+        // we can ignore the case where `rhs` is NaN since
+        // we know that `self` is not NaN.
+        let rhs = (*rhs).into_inner();
+        self.val().total_eq(rhs)
+    }
+}
+impl<F: ToOrd, C: Constraint<F>> Eq for Checked<F, C> {}
+
+impl<F: ToOrd, C: Constraint<F>, Rhs: IntoInner<F> + Copy> PartialOrd<Rhs> for Checked<F, C> {
+    fn partial_cmp(&self, rhs: &Rhs) -> Option<std::cmp::Ordering> {
+        let rhs = (*rhs).into_inner();
+        let rhs = Self::try_new(rhs).ok()?.val().to_ord();
+        let lhs = self.val().to_ord();
+        Some(lhs.cmp(&rhs))
+    }
+}
+impl<F: ToOrd, C: Constraint<F>> Ord for Checked<F, C> {
+    fn cmp(&self, rhs: &Self) -> std::cmp::Ordering {
+        let lhs = self.val().to_ord();
+        let rhs = rhs.val().to_ord();
+        lhs.cmp(&rhs)
+    }
+}
+
+// These can't be implemented for a generic `F` because of coherence rules.
+macro_rules! impl_float_conversions {
+    ($($f: ty),*) => {$(
+        impl<C: Constraint<$f>> TryFrom<$f> for Checked<$f, C> {
+            type Error = C::Error;
+            #[inline]
+            fn try_from(val: $f) -> Result<Self, C::Error> {
+                Self::try_new(val)
+            }
+        }
+        impl<C: Constraint<$f>> From<Checked<$f, C>> for $f {
+            #[inline]
+            fn from(val: Checked<$f, C>) -> Self {
+                val.val()
+            }
+        }
+    )*};
+}
+impl_float_conversions!(f32, f64);
+
+impl<F: ToOrd, C: Constraint<F>> Checked<F, C> {
+    /// Returns the larger of two floating point values.
+    #[must_use]
+    pub fn max(self, other: impl IntoInner<F>) -> Self {
+        let other = other.into_inner();
+        match self.partial_cmp(&other) {
+            Some(std::cmp::Ordering::Less) => unsafe { Self::unchecked(other) },
+            _ => self,
+        }
+    }
+    /// Returns the smaller of two floating point values.
+    #[must_use]
+    pub fn min(self, other: impl IntoInner<F>) -> Self {
+        let other = other.into_inner();
+        match self.partial_cmp(&other) {
+            Some(std::cmp::Ordering::Greater) => unsafe { Self::unchecked(other) },
+            _ => self,
+        }
+    }
+}
+
+// Everything below is only implemented for constraints that opt into `CheckedOps`,
+// since the built-in constraints each have their own hand-tuned implementations.
+
+impl<F: crate::ops::Round, C: Constraint<F> + CheckedOps> Checked<F, C> {
+    /// Attempts to round this floating point number to the previous whole number.
+    /// # Errors
+    /// If the result does not uphold the constraint `C`.
+    pub fn try_floor(self) -> Result<Self, C::Error> {
+        Self::try_new(self.val().floor())
+    }
+    /// Attempts to round this floating point number to the next whole number.
+    /// # Errors
+    /// If the result does not uphold the constraint `C`.
+    pub fn try_ceil(self) -> Result<Self, C::Error> {
+        Self::try_new(self.val().ceil())
+    }
+    /// Attempts to round this floating point number to the nearest whole number.
+    /// # Errors
+    /// If the result does not uphold the constraint `C`.
+    pub fn try_round(self) -> Result<Self, C::Error> {
+        Self::try_new(self.val().round())
+    }
+    /// Attempts to drop the fractional part of this floating point number.
+    /// # Errors
+    /// If the result does not uphold the constraint `C`.
+    pub fn try_trunc(self) -> Result<Self, C::Error> {
+        Self::try_new(self.val().trunc())
+    }
+    /// Attempts to find the fractional part of this floating point number.
+    /// # Errors
+    /// If the result does not uphold the constraint `C`.
+    pub fn try_fract(self) -> Result<Self, C::Error> {
+        Self::try_new(self.val().fract())
+    }
+
+    /// Rounds this floating point number to the previous whole number.
+    /// # Panics
+    /// If the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn floor(self) -> Self {
+        Self::new(self.val().floor())
+    }
+    /// Rounds this floating point number to the next whole number.
+    /// # Panics
+    /// If the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn ceil(self) -> Self {
+        Self::new(self.val().ceil())
+    }
+    /// Rounds this floating point number to the nearest whole number.
+    /// # Panics
+    /// If the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn round(self) -> Self {
+        Self::new(self.val().round())
+    }
+    /// Drops the fractional part of this floating point number.
+    /// # Panics
+    /// If the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn trunc(self) -> Self {
+        Self::new(self.val().trunc())
+    }
+    /// Returns the fractional part of this floating point number.
+    /// # Panics
+    /// If the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn fract(self) -> Self {
+        Self::new(self.val().fract())
+    }
+}
+
+impl<F: crate::ops::Signed, C: Constraint<F> + CheckedOps> Checked<F, C> {
+    /// Attempts to compute the absolute value of self.
+    /// # Errors
+    /// If the result does not uphold the constraint `C`.
+    pub fn try_abs(self) -> Result<Self, C::Error> {
+        Self::try_new(self.val().abs())
+    }
+    /// Computes the absolute value of self.
+    /// # Panics
+    /// If the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn abs(self) -> Self {
+        Self::new(self.val().abs())
+    }
+    /// Attempts to find a number that represents the sign of self.
+    /// # Errors
+    /// If the result does not uphold the constraint `C`.
+    pub fn try_signum(self) -> Result<Self, C::Error> {
+        Self::try_new(self.val().signum())
+    }
+    /// Returns a number that represents the sign of self.
+    /// * `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// * `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// # Panics
+    /// If the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn signum(self) -> Self {
+        Self::new(self.val().signum())
+    }
+    /// Returns true if self has a negative sign, including -0.0 and negative infinity.
+    #[must_use]
+    pub fn is_sign_negative(self) -> bool {
+        self.val().is_sign_negative()
+    }
+    /// Returns true if self has a positive sign, including +0.0 and positive infinity.
+    #[must_use]
+    pub fn is_sign_positive(self) -> bool {
+        self.val().is_sign_positive()
+    }
+}
+
+sum_impls!(
+    @impl [F: Copy, C: Constraint<F> + CheckedOps] Checked<F, C>,
+    C::Error,
+    "If the result does not uphold the constraint `C`."
+);
+neg_impls!(
+    @impl [F: Copy, C: Constraint<F> + CheckedOps] Checked<F, C>,
+    C::Error,
+    "If the result does not uphold the constraint `C`."
+);
+product_impls!(
+    @impl [F: Copy, C: Constraint<F> + CheckedOps] Checked<F, C>,
+    C::Error,
+    "If the result does not uphold the constraint `C`."
+);
+impl<F: crate::ops::Pow, C: Constraint<F> + CheckedOps> Checked<F, C> {
+    pow_methods!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+    recip_methods!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+    sqrt_methods!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+    cbrt_methods!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+    hypot_methods!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+}
+impl<F: crate::ops::Exp, C: Constraint<F> + CheckedOps> Checked<F, C> {
+    exp_methodss!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+    log_methods!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+}
+impl<F: crate::ops::Trig, C: Constraint<F> + CheckedOps> Checked<F, C> {
+    sin_cos_methods!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+    tan_methods!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+    asin_acos_methods!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+    atan_methods!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+    atan2_methods!(
+        F,
+        C::Error,
+        "If the result does not uphold the constraint `C`."
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        constraint::{And, AndError, FiniteC, NonNegC},
+        FiniteNonNeg, InfiniteError, NegativeError,
+    };
+
+    macro_rules! fnn {
+        ($f: expr) => {
+            FiniteNonNeg::new($f)
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
+    fn assert_new_inf() {
+        fnn!(f32::INFINITY);
+    }
+    #[test]
+    #[should_panic(expected = "encountered a negative or NaN unexpectedly")]
+    fn assert_new_neg() {
+        fnn!(-1.0f32);
+    }
+
+    #[test]
+    fn assert_errors() {
+        assert_eq!(
+            FiniteNonNeg::try_new(f32::NAN).unwrap_err(),
+            AndError::First(InfiniteError)
+        );
+        assert_eq!(
+            FiniteNonNeg::try_new(-1.0f32).unwrap_err(),
+            AndError::Second(NegativeError)
+        );
+        // the order of the constraints determines which error takes precedence.
+        assert_eq!(
+            Checked::<f32, And<NonNegC, FiniteC>>::try_new(f32::NEG_INFINITY).unwrap_err(),
+            AndError::First(NegativeError)
+        );
+
+        assert_err!(fnn!(f32::MAX).try_add(f32::MAX));
+        assert_err!(fnn!(1.0f32).try_sub(2.0));
+        assert_err!(fnn!(1.0f32).try_div(0.0));
+        assert_err!(fnn!(1.0f32).try_neg());
+        assert_err!(fnn!(0.5f32).try_ln());
+        assert_err!(fnn!(1000.0f32).try_exp());
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(fnn!(2.0f32) + 1.0, fnn!(3.0));
+        assert_eq!(fnn!(2.0f32) - 1.0, fnn!(1.0));
+        assert_eq!(fnn!(5.0f32) * 2.0, fnn!(10.0));
+        assert_eq!(fnn!(8.0f32) / 2.0, fnn!(4.0));
+        assert_eq!(fnn!(4.0f32).sqrt(), fnn!(2.0));
+        assert_eq!(fnn!(2.5f32).floor(), fnn!(2.0));
+        assert!(fnn!(1.0f32) < fnn!(2.0));
+        assert_eq!(fnn!(1.0f32).max(2.0), fnn!(2.0));
+        assert_eq!(fnn!(1.0f32).min(2.0), fnn!(1.0));
+    }
+
+    #[test]
+    fn assert_max_min() {
+        use crate::Real;
+
+        assert_eq!(Real::new(1.0f32).max(2.0), Real::new(2.0));
+        assert_eq!(Real::new(2.0f32).max(1.0), Real::new(2.0));
+        assert_eq!(Real::new(1.0f32).min(2.0), Real::new(1.0));
+        assert_eq!(Real::new(2.0f32).min(1.0), Real::new(1.0));
+        assert_eq!(
+            Real::new(-0.0f32).max(f32::INFINITY),
+            Real::new(f32::INFINITY)
+        );
+        // a value that does not uphold the constraint is never returned.
+        assert_eq!(fnn!(1.0f32).max(f32::INFINITY), fnn!(1.0));
+        assert_eq!(fnn!(1.0f32).min(-1.0), fnn!(1.0));
+        assert_eq!(fnn!(1.0f32).max(f32::NAN), fnn!(1.0));
+    }
+
+    #[test]
+    fn assert_convert() {
+        use crate::{Finite, NanError, NonNeg, Positive, Real, UnitInterval};
+
+        assert_eq!(Real::try_from(1.0f32), Ok(Real::new(1.0)));
+        assert_eq!(Real::<f64>::try_from(f64::NAN), Err(NanError));
+        assert_eq!(Real::new(2.0), f32::from(fnn!(2.0f32)));
+
+        // widening always succeeds, while narrowing is checked with the narrower type's error.
+        assert_eq!(Real::from(Finite::new(1.0f32)), Real::new(1.0));
+        assert_eq!(Real::from(Positive::new(1.0f32)), Real::new(1.0));
+        assert_eq!(NonNeg::from(UnitInterval::new(0.5f32)), NonNeg::new(0.5));
+        assert_eq!(Finite::from(fnn!(2.0f32)), Finite::new(2.0));
+        assert_eq!(
+            Finite::try_from(Real::new(f32::INFINITY)),
+            Err(InfiniteError)
+        );
+        assert_eq!(NonNeg::try_from(Real::new(-1.0f32)), Err(NegativeError));
+        assert_eq!(
+            FiniteNonNeg::try_from(NonNeg::new(f32::INFINITY)),
+            Err(AndError::First(InfiniteError))
+        );
+        assert_eq!(
+            Positive::try_from(NonNeg::new(2.0f32)),
+            Ok(Positive::new(2.0))
+        );
+    }
+
+    #[test]
+    fn assert_debug() {
+        use crate::{Real, UnitInterval};
+
+        assert_eq!(format!("{:?}", Real::new(1.0f32)), "Real(1.0)");
+        assert_eq!(
+            format!("{:?}", UnitInterval::new(0.5f64)),
+            "UnitInterval(0.5)"
+        );
+        assert_eq!(format!("{:?}", fnn!(2.0f32)), "FiniteNonNeg(2.0)");
+    }
+}
@@ -0,0 +1,412 @@
+use crate::{
+    constraint::Constraint,
+    ops::{Consts, Exp, Pow, Trig},
+    Checked, Finite, IntoInner, IsFinite, IsNegative, IsZero, NonNeg,
+};
+
+/// A complex number, whose real and imaginary parts are checked floating point numbers
+/// such as [`Real`](crate::Real) or [`Finite`].
+///
+/// Every operation checks both parts of the result, and fails with the same error
+/// as the checked type of its parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Complex<T> {
+    /// The real part.
+    pub re: T,
+    /// The imaginary part.
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    /// Creates a new complex number from its real and imaginary parts.
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+#[doc(hidden)]
+pub trait ComplexFloat:
+    IsNegative
+    + IsZero
+    + PartialOrd
+    + Consts
+    + Pow
+    + Exp
+    + Trig
+    + ::core::ops::Add<Output = Self>
+    + ::core::ops::Sub<Output = Self>
+    + ::core::ops::Mul<Output = Self>
+    + ::core::ops::Div<Output = Self>
+    + ::core::ops::Neg<Output = Self>
+{
+}
+impl<T> ComplexFloat for T where
+    T: IsNegative
+        + IsZero
+        + PartialOrd
+        + Consts
+        + Pow
+        + Exp
+        + Trig
+        + ::core::ops::Add<Output = Self>
+        + ::core::ops::Sub<Output = Self>
+        + ::core::ops::Mul<Output = Self>
+        + ::core::ops::Div<Output = Self>
+        + ::core::ops::Neg<Output = Self>
+{
+}
+
+// Every operation is performed on raw floats, and then the two parts of the result are checked.
+// Each method has a private `raw_*` counterpart that does the actual computation.
+impl<F: ComplexFloat, C: Constraint<F>> Complex<Checked<F, C>> {
+    fn try_from_raw((re, im): (F, F)) -> Result<Self, C::Error> {
+        Ok(Self::new(Checked::try_new(re)?, Checked::try_new(im)?))
+    }
+    #[track_caller]
+    fn from_raw((re, im): (F, F)) -> Self {
+        Self::new(Checked::new(re), Checked::new(im))
+    }
+    fn raw(self) -> (F, F) {
+        (self.re.val(), self.im.val())
+    }
+
+    fn raw_mul(self, rhs: Self) -> (F, F) {
+        let ((a, b), (c, d)) = (self.raw(), rhs.raw());
+        (a * c - b * d, a * d + b * c)
+    }
+    fn raw_div(self, rhs: Self) -> (F, F) {
+        let ((a, b), (c, d)) = (self.raw(), rhs.raw());
+        // Smith's algorithm, which avoids overflowing in the intermediate results.
+        if c.abs() >= d.abs() {
+            let ratio = d / c;
+            let den = c + d * ratio;
+            ((a + b * ratio) / den, (b - a * ratio) / den)
+        } else {
+            let ratio = c / d;
+            let den = c * ratio + d;
+            ((a * ratio + b) / den, (b * ratio - a) / den)
+        }
+    }
+    fn raw_exp(self) -> (F, F) {
+        let (re, im) = self.raw();
+        let r = re.exp();
+        if im.is_zero() {
+            // avoids computing `inf * 0` for an infinite real part.
+            return (r, im);
+        }
+        let (sin, cos) = im.sin_cos();
+        (r * cos, r * sin)
+    }
+    fn raw_ln(self) -> (F, F) {
+        let (re, im) = self.raw();
+        (re.hypot(im).ln(), im.atan2(re))
+    }
+    fn raw_sqrt(self) -> (F, F) {
+        let (re, im) = self.raw();
+        let r = re.hypot(im);
+        let two = F::one() + F::one();
+        // only the larger part of the root is computed with `sqrt`, since the smaller part
+        // would suffer from cancellation in `r - |re|`. Both terms are halved before adding them,
+        // so that the sum cannot overflow for large finite inputs.
+        let big = (r / two + re.abs() / two).sqrt();
+        if big.is_zero() {
+            return (big, im);
+        }
+        let small = im / (two * big);
+        if small.is_nan() {
+            // `im` is infinite, and so is the root.
+            return (big, im);
+        }
+        // the imaginary part takes the sign of the input, so this is the principal root.
+        if re.is_sign_negative() {
+            (small.abs(), if im.is_sign_negative() { -big } else { big })
+        } else {
+            (big, small)
+        }
+    }
+    fn raw_powf(self, n: F) -> (F, F) {
+        let (re, im) = self.raw();
+        raw_polar(re.hypot(im).powf(n), im.atan2(re) * n)
+    }
+
+    /// Attempts to compute the complex conjugate.
+    /// # Errors
+    /// If the negated imaginary part does not uphold the constraint `C`.
+    pub fn try_conj(self) -> Result<Self, C::Error> {
+        Ok(Self::new(self.re, Checked::try_new(-self.im.val())?))
+    }
+    /// Computes the complex conjugate.
+    /// # Panics
+    /// If the negated imaginary part does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn conj(self) -> Self {
+        Self::new(self.re, Checked::new(-self.im.val()))
+    }
+
+    /// Computes the magnitude of this complex number.
+    ///
+    /// This will be infinite if the magnitude is too large to be represented.
+    #[must_use]
+    pub fn abs(self) -> NonNeg<F> {
+        NonNeg::new(self.re.val().hypot(self.im.val()))
+    }
+    /// Computes the argument of this complex number, in the range `[-pi, pi]`.
+    #[must_use]
+    pub fn arg(self) -> Finite<F>
+    where
+        F: IsFinite,
+    {
+        // atan2 never produces infinity or NaN, unless one of its inputs is NaN.
+        unsafe { Finite::unchecked(self.im.val().atan2(self.re.val())) }
+    }
+    /// Converts this complex number to polar form, `(abs, arg)`.
+    #[must_use]
+    pub fn to_polar(self) -> (NonNeg<F>, Finite<F>)
+    where
+        F: IsFinite,
+    {
+        (self.abs(), self.arg())
+    }
+    /// Attempts to create a complex number from polar form.
+    /// # Errors
+    /// If either part of the result does not uphold the constraint `C`.
+    pub fn try_from_polar(
+        r: impl IntoInner<F>,
+        theta: impl IntoInner<F>,
+    ) -> Result<Self, C::Error> {
+        Self::try_from_raw(raw_polar(r.into_inner(), theta.into_inner()))
+    }
+    /// Creates a complex number from polar form.
+    /// # Panics
+    /// If either part of the result does not uphold the constraint `C`.
+    #[track_caller]
+    pub fn from_polar(r: impl IntoInner<F>, theta: impl IntoInner<F>) -> Self {
+        Self::from_raw(raw_polar(r.into_inner(), theta.into_inner()))
+    }
+
+    /// Attempts to find `e^(self)`, the exponential function.
+    /// # Errors
+    /// If either part of the result does not uphold the constraint `C`.
+    pub fn try_exp(self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_exp())
+    }
+    /// Finds `e^(self)`, the exponential function.
+    /// # Panics
+    /// If either part of the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn exp(self) -> Self {
+        Self::from_raw(self.raw_exp())
+    }
+    /// Attempts to find the principal value of the natural logarithm.
+    /// # Errors
+    /// If either part of the result does not uphold the constraint `C`.
+    pub fn try_ln(self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_ln())
+    }
+    /// Finds the principal value of the natural logarithm.
+    /// # Panics
+    /// If either part of the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn ln(self) -> Self {
+        Self::from_raw(self.raw_ln())
+    }
+    /// Attempts to find the principal square root.
+    /// # Errors
+    /// If either part of the result does not uphold the constraint `C`.
+    pub fn try_sqrt(self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_sqrt())
+    }
+    /// Finds the principal square root.
+    /// # Panics
+    /// If either part of the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        Self::from_raw(self.raw_sqrt())
+    }
+    /// Attempts to raise this complex number to a real power.
+    /// # Errors
+    /// If either part of the result does not uphold the constraint `C`.
+    pub fn try_powf(self, n: impl IntoInner<F>) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_powf(n.into_inner()))
+    }
+    /// Raises this complex number to a real power.
+    /// # Panics
+    /// If either part of the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn powf(self, n: impl IntoInner<F>) -> Self {
+        Self::from_raw(self.raw_powf(n.into_inner()))
+    }
+
+    /// Attempts to add two complex numbers.
+    /// # Errors
+    /// If either part of the result does not uphold the constraint `C`.
+    pub fn try_add(self, rhs: Self) -> Result<Self, C::Error> {
+        Self::try_from_raw((self.re.val() + rhs.re.val(), self.im.val() + rhs.im.val()))
+    }
+    /// Attempts to subtract two complex numbers.
+    /// # Errors
+    /// If either part of the result does not uphold the constraint `C`.
+    pub fn try_sub(self, rhs: Self) -> Result<Self, C::Error> {
+        Self::try_from_raw((self.re.val() - rhs.re.val(), self.im.val() - rhs.im.val()))
+    }
+    /// Attempts to multiply two complex numbers.
+    /// # Errors
+    /// If either part of the result does not uphold the constraint `C`.
+    pub fn try_mul(self, rhs: Self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_mul(rhs))
+    }
+    /// Attempts to divide two complex numbers.
+    /// # Errors
+    /// If either part of the result does not uphold the constraint `C`.
+    pub fn try_div(self, rhs: Self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_div(rhs))
+    }
+}
+
+fn raw_polar<F: ComplexFloat>(r: F, theta: F) -> (F, F) {
+    let (sin, cos) = theta.sin_cos();
+    (r * cos, r * sin)
+}
+
+impl<F: ComplexFloat, C: Constraint<F>> ::core::ops::Add for Complex<Checked<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_raw((self.re.val() + rhs.re.val(), self.im.val() + rhs.im.val()))
+    }
+}
+impl<F: ComplexFloat, C: Constraint<F>> ::core::ops::Sub for Complex<Checked<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_raw((self.re.val() - rhs.re.val(), self.im.val() - rhs.im.val()))
+    }
+}
+impl<F: ComplexFloat, C: Constraint<F>> ::core::ops::Mul for Complex<Checked<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw_mul(rhs))
+    }
+}
+impl<F: ComplexFloat, C: Constraint<F>> ::core::ops::Div for Complex<Checked<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    fn div(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw_div(rhs))
+    }
+}
+impl<F: ComplexFloat, C: Constraint<F>> ::core::ops::Neg for Complex<Checked<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    fn neg(self) -> Self {
+        Self::from_raw((-self.re.val(), -self.im.val()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InfiniteError, NanError, Real};
+
+    macro_rules! c {
+        ($re: expr, $im: expr) => {
+            Complex::new(Finite::new($re), Finite::new($im))
+        };
+    }
+    macro_rules! assert_complex {
+        ($l: expr, $r: expr) => {{
+            let (l, r) = ($l, $r);
+            assert_epsilon!(l.re, r.re, 1e-6);
+            assert_epsilon!(l.im, r.im, 1e-6);
+        }};
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
+    fn assert_mul_inf() {
+        let _ = c!(f32::MAX, 0.0) * c!(2.0, 0.0);
+    }
+    #[test]
+    #[should_panic(expected = "encountered NaN unexpectedly")]
+    fn assert_mul_nan() {
+        let inf = Complex::new(Real::new(f32::INFINITY), Real::new(0.0));
+        let _ = inf * inf;
+    }
+
+    #[test]
+    fn assert_nan() {
+        assert_eq!(c!(0.0f32, 0.0).try_ln(), Err(InfiniteError));
+        assert_eq!(c!(1.0f32, 1.0).try_div(c!(0.0, 0.0)), Err(InfiniteError));
+        assert_eq!(c!(100.0f32, 0.0).try_exp(), Err(InfiniteError));
+        assert_eq!(
+            c!(f32::MAX, 0.0).try_add(c!(f32::MAX, 0.0)),
+            Err(InfiniteError)
+        );
+
+        let real = |re, im| Complex::new(Real::new(re), Real::new(im));
+        assert_eq!(real(0.0f32, 0.0).try_ln(), Ok(real(f32::NEG_INFINITY, 0.0)));
+        assert_eq!(real(0.0f32, 0.0).try_div(real(0.0, 0.0)), Err(NanError));
+        let inf = f32::INFINITY;
+        assert_eq!(real(-1.0f32, inf).try_sqrt(), Ok(real(inf, inf)));
+        assert_eq!(real(-inf, -1.0f32).try_sqrt(), Ok(real(0.0, -inf)));
+        assert_eq!(real(inf, 1.0f32).try_sqrt(), Ok(real(inf, 0.0)));
+        assert_eq!(real(inf, 0.0f32).try_exp(), Ok(real(inf, 0.0)));
+        assert_eq!(real(inf, -0.0f32).try_exp(), Ok(real(inf, -0.0)));
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(c!(1.0f32, 2.0) + c!(3.0, 4.0), c!(4.0, 6.0));
+        assert_eq!(c!(1.0f32, 2.0) - c!(3.0, 4.0), c!(-2.0, -2.0));
+        assert_eq!(c!(1.0f32, 2.0) * c!(3.0, 4.0), c!(-5.0, 10.0));
+        assert_eq!(c!(-5.0f32, 10.0) / c!(3.0, 4.0), c!(1.0, 2.0));
+        assert_eq!(c!(-5.0f32, 10.0) / c!(1.0, 2.0), c!(3.0, 4.0));
+        assert_eq!(-c!(1.0f32, -2.0), c!(-1.0, 2.0));
+        assert_eq!(c!(1.0f32, -2.0).conj(), c!(1.0, 2.0));
+        // this would overflow without scaling the divisor.
+        assert_complex!(c!(1e30f32, 1e30) / c!(1e30, 1e30), c!(1.0, 0.0));
+    }
+
+    #[test]
+    fn assert_funcs() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        assert_eq!(c!(3.0f32, 4.0).abs(), NonNeg::new(5.0));
+        assert_eq!(c!(0.0f32, 1.0).arg(), Finite::new(FRAC_PI_2));
+        assert_eq!(
+            c!(-1.0f32, 0.0).to_polar(),
+            (NonNeg::new(1.0), Finite::new(PI))
+        );
+        assert_complex!(
+            Complex::<Finite<f32>>::from_polar(2.0, FRAC_PI_2),
+            c!(0.0, 2.0)
+        );
+
+        // Euler's identity
+        assert_complex!(c!(0.0f32, PI).exp(), c!(-1.0, 0.0));
+        assert_complex!(c!(-1.0f32, 0.0).ln(), c!(0.0, PI));
+        assert_complex!(c!(-4.0f32, 0.0).sqrt(), c!(0.0, 2.0));
+        assert_complex!(c!(3.0f32, -4.0).sqrt(), c!(2.0, -1.0));
+        // the smaller part is not lost to cancellation.
+        assert_complex!(c!(1e8f64, 1.0).sqrt(), c!(1e4, 5e-5));
+        assert_complex!(c!(-1e8f64, 1.0).sqrt(), c!(5e-5, 1e4));
+        assert_complex!(c!(-1e8f64, -1.0).sqrt(), c!(5e-5, -1e4));
+        assert_complex!(c!(0.0f32, -0.0).sqrt(), c!(0.0, -0.0));
+        // the intermediate sum does not overflow near the maximum.
+        assert_complex!(
+            c!(f32::MAX, 0.0).sqrt() / c!(f32::MAX.sqrt(), 0.0),
+            c!(1.0, 0.0)
+        );
+        assert_complex!(
+            c!(-2e38f32, 1.0).sqrt() / c!(0.0, 2e38f32.sqrt()),
+            c!(1.0, 0.0)
+        );
+        assert_complex!(c!(0.0f32, 1.0).powf(2.0), c!(-1.0, 0.0));
+    }
+}
@@ -0,0 +1,291 @@
+//! Constraints that can be upheld by a [`Checked`](crate::Checked) floating point number.
+//!
+//! Each of the types in this crate is an alias for `Checked` with one of the constraints
+//! defined here. Constraints can be combined with [`And`]: for example,
+//! `Checked<f32, And<FiniteC, NonNegC>>` is a float that is finite and non-negative.
+//!
+//! You can also define your own constraints by implementing [`Constraint`].
+//! Implement [`CheckedOps`] as well to get the full set of (checked) arithmetic operations.
+
+use std::fmt;
+
+use crate::{
+    Bounds, BoundsError, InfiniteError, IsFinite, IsLogProbability, IsNan, IsNegative, IsNonZero,
+    IsPositive, IsSignedUnit, IsSubnormal, IsUnitInterval, LogProbabilityError, NanError,
+    NegativeError, NonPosError, NormalError, NotNegativeError, PositiveError, SignedUnitError,
+    ToOrd, UnitIntervalError, ZeroError,
+};
+
+/// An invariant that is upheld by a [`Checked`](crate::Checked) floating point number.
+pub trait Constraint<F> {
+    /// The error produced when a value does not uphold this constraint.
+    type Error: fmt::Debug + fmt::Display;
+    /// Checks whether or not a value upholds this constraint.
+    /// # Errors
+    /// If the value does not uphold this constraint.
+    fn check(val: F) -> Result<(), Self::Error>;
+    /// Writes the name of the checked type, such as `Real` or `Finite`, which is used by its
+    /// `Debug` implementation. Defaults to `Checked`.
+    /// # Errors
+    /// If the formatter returns an error.
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Checked")
+    }
+}
+
+/// Marker for constraints that use the generic implementation of every operation,
+/// which checks the result against the constraint each time.
+///
+/// The constraints built into this crate do not implement this trait, as each of them
+/// have hand-tuned implementations that skip checks where they are known to be unnecessary.
+pub trait CheckedOps {}
+
+/// A constraint that is upheld if both `A` and `B` are upheld.
+pub struct And<A, B>(std::marker::PhantomData<(A, B)>);
+
+/// The error produced when a value does not uphold an [`And`] constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AndError<A, B> {
+    /// The first constraint was not upheld.
+    First(A),
+    /// The second constraint was not upheld.
+    Second(B),
+}
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for AndError<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::First(e) => e.fmt(f),
+            Self::Second(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<F: Copy, A: Constraint<F>, B: Constraint<F>> Constraint<F> for And<A, B> {
+    type Error = AndError<A::Error, B::Error>;
+    #[inline]
+    fn check(val: F) -> Result<(), Self::Error> {
+        A::check(val).map_err(AndError::First)?;
+        B::check(val).map_err(AndError::Second)
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        A::fmt_name(f)?;
+        B::fmt_name(f)
+    }
+}
+impl<A, B> CheckedOps for And<A, B> {}
+
+/// The constraint for [`Real`](crate::Real): the value must not be NaN.
+pub enum RealC {}
+impl<F: IsNan> Constraint<F> for RealC {
+    type Error = NanError;
+    #[inline]
+    fn check(val: F) -> Result<(), NanError> {
+        if val.is_nan() {
+            Err(NanError)
+        } else {
+            Ok(())
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Real")
+    }
+}
+
+/// The constraint for [`Finite`](crate::Finite): the value must not be infinite or NaN.
+pub enum FiniteC {}
+impl<F: IsFinite> Constraint<F> for FiniteC {
+    type Error = InfiniteError;
+    #[inline]
+    fn check(val: F) -> Result<(), InfiniteError> {
+        if val.is_finite() {
+            Ok(())
+        } else {
+            Err(InfiniteError)
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Finite")
+    }
+}
+
+/// The constraint for [`NonNeg`](crate::NonNeg): the value must have a positive sign and not be NaN.
+pub enum NonNegC {}
+impl<F: IsNegative> Constraint<F> for NonNegC {
+    type Error = NegativeError;
+    #[inline]
+    fn check(val: F) -> Result<(), NegativeError> {
+        if val.is_sign_negative() || val.is_nan() {
+            Err(NegativeError)
+        } else {
+            Ok(())
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NonNeg")
+    }
+}
+
+/// The constraint for [`NonPos`](crate::NonPos): the value must be zero (of either sign) or negative,
+/// and not NaN.
+pub enum NonPosC {}
+impl<F: IsPositive> Constraint<F> for NonPosC {
+    type Error = NonPosError;
+    #[inline]
+    fn check(val: F) -> Result<(), NonPosError> {
+        if (val.is_sign_positive() && !val.is_zero()) || val.is_nan() {
+            Err(NonPosError)
+        } else {
+            Ok(())
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NonPos")
+    }
+}
+
+/// The constraint for [`Positive`](crate::Positive): the value must be greater than zero.
+pub enum PositiveC {}
+impl<F: IsPositive> Constraint<F> for PositiveC {
+    type Error = PositiveError;
+    #[inline]
+    fn check(val: F) -> Result<(), PositiveError> {
+        if val.is_sign_negative() || val.is_nan() || val.is_zero() {
+            Err(PositiveError)
+        } else {
+            Ok(())
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Positive")
+    }
+}
+
+/// The constraint for [`Negative`](crate::Negative): the value must be less than zero.
+pub enum NegativeC {}
+impl<F: IsPositive> Constraint<F> for NegativeC {
+    type Error = NotNegativeError;
+    #[inline]
+    fn check(val: F) -> Result<(), NotNegativeError> {
+        if val.is_sign_positive() || val.is_nan() || val.is_zero() {
+            Err(NotNegativeError)
+        } else {
+            Ok(())
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Negative")
+    }
+}
+
+/// The constraint for [`NonZero`](crate::NonZero): the value must not be zero or NaN.
+pub enum NonZeroC {}
+impl<F: IsNonZero> Constraint<F> for NonZeroC {
+    type Error = ZeroError;
+    #[inline]
+    fn check(val: F) -> Result<(), ZeroError> {
+        if val.is_nan() || val.is_zero() {
+            Err(ZeroError)
+        } else {
+            Ok(())
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NonZero")
+    }
+}
+
+/// The constraint for [`UnitInterval`](crate::UnitInterval): the value must be within `[0, 1]`.
+pub enum UnitIntervalC {}
+impl<F: IsUnitInterval> Constraint<F> for UnitIntervalC {
+    type Error = UnitIntervalError;
+    #[inline]
+    fn check(val: F) -> Result<(), UnitIntervalError> {
+        if val.is_unit_interval() {
+            Ok(())
+        } else {
+            Err(UnitIntervalError)
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "UnitInterval")
+    }
+}
+
+/// The constraint for [`SignedUnit`](crate::SignedUnit): the value must be within `[-1, 1]`.
+pub enum SignedUnitC {}
+impl<F: IsSignedUnit> Constraint<F> for SignedUnitC {
+    type Error = SignedUnitError;
+    #[inline]
+    fn check(val: F) -> Result<(), SignedUnitError> {
+        if val.is_signed_unit() {
+            Ok(())
+        } else {
+            Err(SignedUnitError)
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SignedUnit")
+    }
+}
+
+/// The constraint for [`Normal`](crate::Normal): the value must be finite, and either zero or normal.
+pub enum NormalC {}
+impl<F: IsSubnormal> Constraint<F> for NormalC {
+    type Error = NormalError;
+    #[inline]
+    fn check(val: F) -> Result<(), NormalError> {
+        if !val.is_finite() {
+            Err(NormalError::NonFinite)
+        } else if val.is_subnormal() {
+            Err(NormalError::Subnormal)
+        } else {
+            Ok(())
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Normal")
+    }
+}
+
+/// The constraint for [`LogProbability`](crate::LogProbability): the value must be less than or equal to zero.
+pub enum LogProbabilityC {}
+impl<F: IsLogProbability> Constraint<F> for LogProbabilityC {
+    type Error = LogProbabilityError;
+    #[inline]
+    fn check(val: F) -> Result<(), LogProbabilityError> {
+        if val.is_log_probability() {
+            Ok(())
+        } else {
+            Err(LogProbabilityError)
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LogProbability")
+    }
+}
+
+/// The constraint for [`Bounded`](crate::Bounded): the value must be within `[B::MIN, B::MAX]`.
+pub struct BoundedC<B>(std::marker::PhantomData<B>);
+impl<F, B> Constraint<F> for BoundedC<B>
+where
+    F: IsNan + ToOrd + fmt::Debug + fmt::Display,
+    B: Bounds<F>,
+{
+    type Error = BoundsError<F>;
+    #[inline]
+    fn check(val: F) -> Result<(), BoundsError<F>> {
+        if val.is_nan() || val.to_ord() < B::MIN.to_ord() || val.to_ord() > B::MAX.to_ord() {
+            Err(BoundsError {
+                min: B::MIN,
+                max: B::MAX,
+            })
+        } else {
+            Ok(())
+        }
+    }
+    fn fmt_name(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Bounded")
+    }
+}
+// the bounds are arbitrary, so each operation needs to be checked.
+impl<B> CheckedOps for BoundedC<B> {}
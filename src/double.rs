@@ -0,0 +1,301 @@
+use std::cmp::Ordering;
+
+use crate::{
+    ops::{MulAdd, Pow},
+    Finite, InfiniteError, IsFinite, IsZero, ToOrd,
+};
+
+#[doc(hidden)]
+pub trait DoubleFloat:
+    IsFinite
+    + IsZero
+    + ToOrd
+    + Default
+    + MulAdd
+    + Pow
+    + ::core::ops::Add<Output = Self>
+    + ::core::ops::Sub<Output = Self>
+    + ::core::ops::Mul<Output = Self>
+    + ::core::ops::Div<Output = Self>
+    + ::core::ops::Neg<Output = Self>
+{
+}
+impl<T> DoubleFloat for T where
+    T: IsFinite
+        + IsZero
+        + ToOrd
+        + Default
+        + MulAdd
+        + Pow
+        + ::core::ops::Add<Output = Self>
+        + ::core::ops::Sub<Output = Self>
+        + ::core::ops::Mul<Output = Self>
+        + ::core::ops::Div<Output = Self>
+        + ::core::ops::Neg<Output = Self>
+{
+}
+
+/// Computes `a + b`, along with the exact rounding error of the sum.
+fn two_sum<F: DoubleFloat>(a: F, b: F) -> (F, F) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+/// Like [`two_sum`], but only valid if `|a| >= |b|`.
+fn quick_two_sum<F: DoubleFloat>(a: F, b: F) -> (F, F) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+/// Computes `a * b`, along with the exact rounding error of the product.
+fn two_prod<F: DoubleFloat>(a: F, b: F) -> (F, F) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+/// A floating point number with roughly twice the precision of `F`,
+/// represented as the unevaluated sum of two [`Finite`] numbers.
+///
+/// The high part is always the sum of both parts rounded to the nearest `F`,
+/// and the low part holds the rounding error. For `f64`, this gives about 32 significant digits.
+#[derive(Debug, Clone, Copy)]
+pub struct DoubleReal<F: IsFinite> {
+    hi: Finite<F>,
+    lo: Finite<F>,
+}
+
+impl<F: DoubleFloat> DoubleReal<F> {
+    /// Checks the result of an operation.
+    fn try_from_raw((hi, lo): (F, F)) -> Result<Self, InfiniteError> {
+        Ok(Self {
+            hi: Finite::try_new(hi)?,
+            lo: Finite::try_new(lo)?,
+        })
+    }
+    fn raw(self) -> (F, F) {
+        (self.hi.val(), self.lo.val())
+    }
+
+    /// Attempts to create a new number from the sum of two parts, which may overlap.
+    /// # Errors
+    /// If the sum is non-finite.
+    pub fn try_from_parts(hi: Finite<F>, lo: Finite<F>) -> Result<Self, InfiniteError> {
+        Self::try_from_raw(two_sum(hi.val(), lo.val()))
+    }
+    /// Creates a new number from the sum of two parts, which may overlap.
+    /// # Panics
+    /// If the sum is non-finite.
+    #[track_caller]
+    pub fn from_parts(hi: Finite<F>, lo: Finite<F>) -> Self {
+        crate::unwrap_display(Self::try_from_parts(hi, lo))
+    }
+    /// Gets the high part of this number, which is its value rounded to the nearest `F`.
+    #[inline]
+    pub fn hi(self) -> Finite<F> {
+        self.hi
+    }
+    /// Gets the low part of this number, which is the rounding error of the high part.
+    #[inline]
+    pub fn lo(self) -> Finite<F> {
+        self.lo
+    }
+    /// Rounds this number to the nearest `F`.
+    #[inline]
+    pub fn to_finite(self) -> Finite<F> {
+        self.hi
+    }
+
+    /// Attempts to add two numbers.
+    /// # Errors
+    /// If the result is non-finite.
+    pub fn try_add(self, rhs: Self) -> Result<Self, InfiniteError> {
+        let (a, b) = (self.raw(), rhs.raw());
+        let (s1, s2) = two_sum(a.0, b.0);
+        let (t1, t2) = two_sum(a.1, b.1);
+        let (s1, s2) = quick_two_sum(s1, s2 + t1);
+        Self::try_from_raw(quick_two_sum(s1, s2 + t2))
+    }
+    /// Attempts to subtract two numbers.
+    /// # Errors
+    /// If the result is non-finite.
+    pub fn try_sub(self, rhs: Self) -> Result<Self, InfiniteError> {
+        self.try_add(-rhs)
+    }
+    /// Attempts to multiply two numbers.
+    /// # Errors
+    /// If the result is non-finite.
+    pub fn try_mul(self, rhs: Self) -> Result<Self, InfiniteError> {
+        let (a, b) = (self.raw(), rhs.raw());
+        let (p1, p2) = two_prod(a.0, b.0);
+        let p2 = p2 + (a.0 * b.1 + a.1 * b.0);
+        Self::try_from_raw(quick_two_sum(p1, p2))
+    }
+    /// Attempts to divide two numbers.
+    /// # Errors
+    /// If the result is non-finite, including when dividing by zero.
+    pub fn try_div(self, rhs: Self) -> Result<Self, InfiniteError> {
+        // long division, where each step refines the quotient by another `F` of precision.
+        let q1 = self.hi.val() / rhs.hi.val();
+        let r = self.try_sub(rhs.try_mul_raw(q1)?)?;
+        let q2 = r.hi.val() / rhs.hi.val();
+        let r = r.try_sub(rhs.try_mul_raw(q2)?)?;
+        let q3 = r.hi.val() / rhs.hi.val();
+        Self::try_from_raw(quick_two_sum(q1, q2))?.try_add(Self::from(Finite::try_new(q3)?))
+    }
+    fn try_mul_raw(self, rhs: F) -> Result<Self, InfiniteError> {
+        let (p1, p2) = two_prod(self.hi.val(), rhs);
+        Self::try_from_raw(quick_two_sum(p1, p2 + self.lo.val() * rhs))
+    }
+    /// Attempts to find the square root of this number.
+    /// # Errors
+    /// If this number is negative.
+    pub fn try_sqrt(self) -> Result<Self, InfiniteError> {
+        let (hi, lo) = self.raw();
+        if hi.is_zero() {
+            return Ok(self);
+        }
+        // a single newton step from the `F` approximation doubles the precision.
+        let y = hi.sqrt();
+        let (sq1, sq2) = two_prod(y, y);
+        let r = ((hi - sq1) - sq2) + lo;
+        Self::try_from_raw(two_sum(y, r / (y + y)))
+    }
+    /// Finds the square root of this number.
+    /// # Panics
+    /// If this number is negative.
+    #[track_caller]
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        crate::unwrap_display(self.try_sqrt())
+    }
+}
+
+impl<F: DoubleFloat> From<Finite<F>> for DoubleReal<F> {
+    fn from(val: Finite<F>) -> Self {
+        Self {
+            hi: val,
+            lo: Finite::new(F::default()),
+        }
+    }
+}
+impl<F: DoubleFloat> From<DoubleReal<F>> for Finite<F> {
+    #[inline]
+    fn from(val: DoubleReal<F>) -> Self {
+        val.to_finite()
+    }
+}
+
+impl<F: IsFinite + ToOrd> PartialEq for DoubleReal<F> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.hi == rhs.hi && self.lo == rhs.lo
+    }
+}
+impl<F: IsFinite + ToOrd> Eq for DoubleReal<F> {}
+impl<F: IsFinite + ToOrd> PartialOrd for DoubleReal<F> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+impl<F: IsFinite + ToOrd> Ord for DoubleReal<F> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.hi.cmp(&rhs.hi).then_with(|| self.lo.cmp(&rhs.lo))
+    }
+}
+
+impl<F: DoubleFloat> ::core::ops::Add for DoubleReal<F> {
+    type Output = Self;
+    #[track_caller]
+    fn add(self, rhs: Self) -> Self {
+        crate::unwrap_display(self.try_add(rhs))
+    }
+}
+impl<F: DoubleFloat> ::core::ops::Sub for DoubleReal<F> {
+    type Output = Self;
+    #[track_caller]
+    fn sub(self, rhs: Self) -> Self {
+        crate::unwrap_display(self.try_sub(rhs))
+    }
+}
+impl<F: DoubleFloat> ::core::ops::Mul for DoubleReal<F> {
+    type Output = Self;
+    #[track_caller]
+    fn mul(self, rhs: Self) -> Self {
+        crate::unwrap_display(self.try_mul(rhs))
+    }
+}
+impl<F: DoubleFloat> ::core::ops::Div for DoubleReal<F> {
+    type Output = Self;
+    #[track_caller]
+    fn div(self, rhs: Self) -> Self {
+        crate::unwrap_display(self.try_div(rhs))
+    }
+}
+impl<F: DoubleFloat> ::core::ops::Neg for DoubleReal<F> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        // negation is exact, so both parts stay finite.
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! dd {
+        ($f: expr) => {
+            DoubleReal::from(Finite::new($f))
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
+    fn assert_div_zero() {
+        let _ = dd!(1.0f64) / dd!(0.0);
+    }
+    #[test]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
+    fn assert_sqrt_neg() {
+        let _ = dd!(-1.0f64).sqrt();
+    }
+
+    #[test]
+    fn assert_ops() {
+        // the low part keeps track of what doesn't fit in the high part.
+        let big = dd!(1e16f64);
+        let sum = big + dd!(1.0);
+        assert_eq!(sum.hi(), Finite::new(1e16));
+        assert_eq!(sum.lo(), Finite::new(1.0));
+        assert_eq!(sum - big, dd!(1.0));
+
+        // 0.1 + 0.2 - 0.3 is exactly the rounding error in the literals.
+        let err = dd!(0.1f64) + dd!(0.2) - dd!(0.3);
+        assert_eq!(err.to_finite(), Finite::new(2.775_557_561_562_891_4e-17));
+
+        let third = dd!(1.0f64) / dd!(3.0);
+        assert_eq!(third.hi(), Finite::new(1.0 / 3.0));
+        assert!(third.lo() != Finite::new(0.0));
+        let one = third * dd!(3.0) - dd!(1.0);
+        assert_epsilon!(one.to_finite(), Finite::new(0.0), 1e-31);
+
+        assert_eq!(-dd!(2.0f64), dd!(-2.0));
+        assert_eq!(dd!(0.0f64).sqrt(), dd!(0.0));
+        let root = dd!(2.0f64).sqrt();
+        assert_epsilon!(
+            (root * root - dd!(2.0)).to_finite(),
+            Finite::new(0.0),
+            1e-31
+        );
+    }
+
+    #[test]
+    fn assert_cmp() {
+        let sum = dd!(1.0f64) + DoubleReal::from_parts(Finite::new(0.0), Finite::new(1e-20));
+        assert!(sum > dd!(1.0));
+        assert!(sum < dd!(1.0) + dd!(1e-19));
+        assert_eq!(sum.to_finite(), Finite::new(1.0));
+        assert_eq!(Finite::from(sum), Finite::new(1.0));
+    }
+}
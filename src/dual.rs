@@ -0,0 +1,510 @@
+use crate::{
+    constraint::Constraint,
+    ops::{Consts, Exp, Pow, Signed, Trig},
+    Checked, IntoInner, IsNan,
+};
+
+/// A dual number, which carries a checked floating point value along with its derivative,
+/// for forward-mode automatic differentiation.
+///
+/// To differentiate a function, pass it a [`Dual::variable`] and read the `deriv` of the result.
+/// Every operation checks both the value and the derivative of the result, and fails with the
+/// same error as the checked type `T`. Note that the derivative must uphold the same constraint
+/// as the value, so this is most useful with [`Real`](crate::Real) or [`Finite`](crate::Finite).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Dual<T> {
+    /// The value.
+    pub value: T,
+    /// The derivative of the value.
+    pub deriv: T,
+}
+
+impl<T> Dual<T> {
+    /// Creates a new dual number from a value and its derivative.
+    pub const fn new(value: T, deriv: T) -> Self {
+        Self { value, deriv }
+    }
+}
+
+#[doc(hidden)]
+pub trait DualFloat:
+    IsNan
+    + Default
+    + Consts
+    + Signed
+    + Pow
+    + Exp
+    + Trig
+    + ::core::ops::Add<Output = Self>
+    + ::core::ops::Sub<Output = Self>
+    + ::core::ops::Mul<Output = Self>
+    + ::core::ops::Div<Output = Self>
+    + ::core::ops::Neg<Output = Self>
+{
+}
+impl<T> DualFloat for T where
+    T: IsNan
+        + Default
+        + Consts
+        + Signed
+        + Pow
+        + Exp
+        + Trig
+        + ::core::ops::Add<Output = Self>
+        + ::core::ops::Sub<Output = Self>
+        + ::core::ops::Mul<Output = Self>
+        + ::core::ops::Div<Output = Self>
+        + ::core::ops::Neg<Output = Self>
+{
+}
+
+/// Defines a fallible and a panicking method from a private `raw_*` method.
+macro_rules! dual_methods {
+    ($(
+        $(#[doc = $doc: literal])*
+        fn $name: ident, $try_name: ident => $raw: ident $(($arg: ident))?;
+    )*) => {
+        $(
+            $(#[doc = $doc])*
+            /// # Errors
+            /// If either the value or the derivative does not uphold the constraint `C`.
+            pub fn $try_name(self $(, $arg: impl IntoInner<F>)?) -> Result<Self, C::Error> {
+                Self::try_from_raw(self.$raw($($arg.into_inner())?))
+            }
+            $(#[doc = $doc])*
+            /// # Panics
+            /// If either the value or the derivative does not uphold the constraint `C`.
+            #[track_caller]
+            #[must_use]
+            pub fn $name(self $(, $arg: impl IntoInner<F>)?) -> Self {
+                Self::from_raw(self.$raw($($arg.into_inner())?))
+            }
+        )*
+    };
+}
+
+// Every operation is performed on raw floats, and then the value and derivative are checked.
+// Each method has a private `raw_*` counterpart that applies the chain rule.
+impl<F: DualFloat, C: Constraint<F>> Dual<Checked<F, C>> {
+    fn try_from_raw((value, deriv): (F, F)) -> Result<Self, C::Error> {
+        Ok(Self::new(
+            Checked::try_new(value)?,
+            Checked::try_new(deriv)?,
+        ))
+    }
+    #[track_caller]
+    fn from_raw((value, deriv): (F, F)) -> Self {
+        Self::new(Checked::new(value), Checked::new(deriv))
+    }
+    fn raw(self) -> (F, F) {
+        (self.value.val(), self.deriv.val())
+    }
+
+    /// Attempts to create a dual number for a constant, whose derivative is zero.
+    /// # Errors
+    /// If either the value or zero does not uphold the constraint `C`.
+    pub fn try_constant(value: impl IntoInner<F>) -> Result<Self, C::Error> {
+        Self::try_from_raw((value.into_inner(), F::default()))
+    }
+    /// Creates a dual number for a constant, whose derivative is zero.
+    /// # Panics
+    /// If either the value or zero does not uphold the constraint `C`.
+    #[track_caller]
+    pub fn constant(value: impl IntoInner<F>) -> Self {
+        Self::from_raw((value.into_inner(), F::default()))
+    }
+    /// Attempts to create a dual number for the variable being differentiated,
+    /// whose derivative is one.
+    /// # Errors
+    /// If either the value or one does not uphold the constraint `C`.
+    pub fn try_variable(value: impl IntoInner<F>) -> Result<Self, C::Error> {
+        let value = value.into_inner();
+        Self::try_from_raw((value, F::one()))
+    }
+    /// Creates a dual number for the variable being differentiated, whose derivative is one.
+    /// # Panics
+    /// If either the value or one does not uphold the constraint `C`.
+    #[track_caller]
+    pub fn variable(value: impl IntoInner<F>) -> Self {
+        let value = value.into_inner();
+        Self::from_raw((value, F::one()))
+    }
+
+    fn raw_add(self, rhs: Self) -> (F, F) {
+        let ((a, da), (b, db)) = (self.raw(), rhs.raw());
+        (a + b, da + db)
+    }
+    fn raw_sub(self, rhs: Self) -> (F, F) {
+        let ((a, da), (b, db)) = (self.raw(), rhs.raw());
+        (a - b, da - db)
+    }
+    fn raw_mul(self, rhs: Self) -> (F, F) {
+        let ((a, da), (b, db)) = (self.raw(), rhs.raw());
+        (a * b, da * b + a * db)
+    }
+    fn raw_div(self, rhs: Self) -> (F, F) {
+        let ((a, da), (b, db)) = (self.raw(), rhs.raw());
+        let q = a / b;
+        (q, (da - q * db) / b)
+    }
+    fn raw_neg(self) -> (F, F) {
+        let (a, da) = self.raw();
+        (-a, -da)
+    }
+    fn raw_abs(self) -> (F, F) {
+        let (a, da) = self.raw();
+        (a.abs(), a.signum() * da)
+    }
+    fn raw_recip(self) -> (F, F) {
+        let (a, da) = self.raw();
+        let r = a.recip();
+        (r, -da * r * r)
+    }
+    fn raw_powf(self, n: F) -> (F, F) {
+        let (a, da) = self.raw();
+        (a.powf(n), n * a.powf(n - F::one()) * da)
+    }
+    fn raw_sqrt(self) -> (F, F) {
+        let (a, da) = self.raw();
+        let s = a.sqrt();
+        (s, da / (s + s))
+    }
+    fn raw_cbrt(self) -> (F, F) {
+        let (a, da) = self.raw();
+        let c = a.cbrt();
+        let three = F::one() + F::one() + F::one();
+        (c, da / (three * c * c))
+    }
+    fn raw_hypot(self, rhs: Self) -> (F, F) {
+        let ((a, da), (b, db)) = (self.raw(), rhs.raw());
+        let h = a.hypot(b);
+        (h, (a * da + b * db) / h)
+    }
+    fn raw_exp(self) -> (F, F) {
+        let (a, da) = self.raw();
+        let e = a.exp();
+        (e, e * da)
+    }
+    fn raw_exp2(self) -> (F, F) {
+        let (a, da) = self.raw();
+        let e = a.exp2();
+        let ln_2 = (F::one() + F::one()).ln();
+        (e, e * ln_2 * da)
+    }
+    fn raw_exp_m1(self) -> (F, F) {
+        let (a, da) = self.raw();
+        (a.exp_m1(), a.exp() * da)
+    }
+    fn raw_ln(self) -> (F, F) {
+        let (a, da) = self.raw();
+        (a.ln(), da / a)
+    }
+    fn raw_log(self, base: F) -> (F, F) {
+        let (a, da) = self.raw();
+        (a.log(base), da / (a * base.ln()))
+    }
+    fn raw_log2(self) -> (F, F) {
+        let (a, da) = self.raw();
+        let ln_2 = (F::one() + F::one()).ln();
+        (a.log2(), da / (a * ln_2))
+    }
+    fn raw_log10(self) -> (F, F) {
+        let (a, da) = self.raw();
+        let two = F::one() + F::one();
+        let ln_10 = (two * two * two + two).ln();
+        (a.log10(), da / (a * ln_10))
+    }
+    fn raw_ln_1p(self) -> (F, F) {
+        let (a, da) = self.raw();
+        (a.ln_1p(), da / (F::one() + a))
+    }
+    fn raw_sin(self) -> (F, F) {
+        let (a, da) = self.raw();
+        let (sin, cos) = a.sin_cos();
+        (sin, cos * da)
+    }
+    fn raw_cos(self) -> (F, F) {
+        let (a, da) = self.raw();
+        let (sin, cos) = a.sin_cos();
+        (cos, -sin * da)
+    }
+    fn raw_tan(self) -> (F, F) {
+        let (a, da) = self.raw();
+        let t = a.tan();
+        (t, (F::one() + t * t) * da)
+    }
+    fn raw_asin(self) -> (F, F) {
+        let (a, da) = self.raw();
+        (a.asin(), da / (F::one() - a * a).sqrt())
+    }
+    fn raw_acos(self) -> (F, F) {
+        let (a, da) = self.raw();
+        (a.acos(), -da / (F::one() - a * a).sqrt())
+    }
+    fn raw_atan(self) -> (F, F) {
+        let (a, da) = self.raw();
+        (a.atan(), da / (F::one() + a * a))
+    }
+    fn raw_atan2(self, rhs: Self) -> (F, F) {
+        let ((y, dy), (x, dx)) = (self.raw(), rhs.raw());
+        (y.atan2(x), (x * dy - y * dx) / (x * x + y * y))
+    }
+
+    dual_methods! {
+        /// Attempts to compute the absolute value.
+        fn abs, try_abs => raw_abs;
+        /// Computes `1 / self`.
+        fn recip, try_recip => raw_recip;
+        /// Raises this number to a constant power.
+        fn powf, try_powf => raw_powf(n);
+        /// Computes the square root.
+        fn sqrt, try_sqrt => raw_sqrt;
+        /// Computes the cube root.
+        fn cbrt, try_cbrt => raw_cbrt;
+        /// Computes `e^(self)`.
+        fn exp, try_exp => raw_exp;
+        /// Computes `2^(self)`.
+        fn exp2, try_exp2 => raw_exp2;
+        /// Computes `e^(self) - 1`.
+        fn exp_m1, try_exp_m1 => raw_exp_m1;
+        /// Computes the natural logarithm.
+        fn ln, try_ln => raw_ln;
+        /// Computes the logarithm with respect to a constant base.
+        fn log, try_log => raw_log(base);
+        /// Computes the base 2 logarithm.
+        fn log2, try_log2 => raw_log2;
+        /// Computes the base 10 logarithm.
+        fn log10, try_log10 => raw_log10;
+        /// Computes `ln(1 + self)`.
+        fn ln_1p, try_ln_1p => raw_ln_1p;
+        /// Computes the sine.
+        fn sin, try_sin => raw_sin;
+        /// Computes the cosine.
+        fn cos, try_cos => raw_cos;
+        /// Computes the tangent.
+        fn tan, try_tan => raw_tan;
+        /// Computes the arcsine.
+        fn asin, try_asin => raw_asin;
+        /// Computes the arccosine.
+        fn acos, try_acos => raw_acos;
+        /// Computes the arctangent.
+        fn atan, try_atan => raw_atan;
+    }
+
+    /// Attempts to compute the sine and cosine at once.
+    /// # Errors
+    /// If either the value or the derivative of either result does not uphold the constraint `C`.
+    pub fn try_sin_cos(self) -> Result<(Self, Self), C::Error> {
+        let (a, da) = self.raw();
+        let (sin, cos) = a.sin_cos();
+        Ok((
+            Self::try_from_raw((sin, cos * da))?,
+            Self::try_from_raw((cos, -sin * da))?,
+        ))
+    }
+    /// Computes the sine and cosine at once.
+    /// # Panics
+    /// If either the value or the derivative of either result does not uphold the constraint `C`.
+    #[track_caller]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (a, da) = self.raw();
+        let (sin, cos) = a.sin_cos();
+        (
+            Self::from_raw((sin, cos * da)),
+            Self::from_raw((cos, -sin * da)),
+        )
+    }
+    /// Attempts to compute the four quadrant arctangent of `self` (`y`) and `other` (`x`).
+    /// # Errors
+    /// If either the value or the derivative does not uphold the constraint `C`.
+    pub fn try_atan2(self, other: Self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_atan2(other))
+    }
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`).
+    /// # Panics
+    /// If either the value or the derivative does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn atan2(self, other: Self) -> Self {
+        Self::from_raw(self.raw_atan2(other))
+    }
+    /// Attempts to compute the length of the hypotenuse of a right triangle.
+    /// # Errors
+    /// If either the value or the derivative does not uphold the constraint `C`.
+    pub fn try_hypot(self, other: Self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_hypot(other))
+    }
+    /// Computes the length of the hypotenuse of a right triangle.
+    /// # Panics
+    /// If either the value or the derivative does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn hypot(self, other: Self) -> Self {
+        Self::from_raw(self.raw_hypot(other))
+    }
+
+    /// Attempts to add two dual numbers.
+    /// # Errors
+    /// If either the value or the derivative does not uphold the constraint `C`.
+    pub fn try_add(self, rhs: Self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_add(rhs))
+    }
+    /// Attempts to subtract two dual numbers.
+    /// # Errors
+    /// If either the value or the derivative does not uphold the constraint `C`.
+    pub fn try_sub(self, rhs: Self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_sub(rhs))
+    }
+    /// Attempts to multiply two dual numbers.
+    /// # Errors
+    /// If either the value or the derivative does not uphold the constraint `C`.
+    pub fn try_mul(self, rhs: Self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_mul(rhs))
+    }
+    /// Attempts to divide two dual numbers.
+    /// # Errors
+    /// If either the value or the derivative does not uphold the constraint `C`.
+    pub fn try_div(self, rhs: Self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_div(rhs))
+    }
+    /// Attempts to negate a dual number.
+    /// # Errors
+    /// If either the value or the derivative does not uphold the constraint `C`.
+    pub fn try_neg(self) -> Result<Self, C::Error> {
+        Self::try_from_raw(self.raw_neg())
+    }
+}
+
+impl<F: DualFloat, C: Constraint<F>> ::core::ops::Add for Dual<Checked<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw_add(rhs))
+    }
+}
+impl<F: DualFloat, C: Constraint<F>> ::core::ops::Sub for Dual<Checked<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw_sub(rhs))
+    }
+}
+impl<F: DualFloat, C: Constraint<F>> ::core::ops::Mul for Dual<Checked<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw_mul(rhs))
+    }
+}
+impl<F: DualFloat, C: Constraint<F>> ::core::ops::Div for Dual<Checked<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    fn div(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw_div(rhs))
+    }
+}
+impl<F: DualFloat, C: Constraint<F>> ::core::ops::Neg for Dual<Checked<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    fn neg(self) -> Self {
+        Self::from_raw(self.raw_neg())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Finite, InfiniteError, NanError, Real};
+
+    macro_rules! var {
+        ($f: expr) => {
+            Dual::<Finite<f64>>::variable($f)
+        };
+    }
+    macro_rules! konst {
+        ($f: expr) => {
+            Dual::<Finite<f64>>::constant($f)
+        };
+    }
+    /// Asserts that the value and derivative of `$d` are close to `$v` and `$dv`.
+    macro_rules! assert_dual {
+        ($d: expr, $v: expr, $dv: expr) => {{
+            let d = $d;
+            assert_epsilon!(d.value, Finite::new($v), 1e-12);
+            assert_epsilon!(d.deriv, Finite::new($dv), 1e-12);
+        }};
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
+    fn assert_sqrt_zero() {
+        // the value is fine, but the derivative is infinite.
+        let _ = var!(0.0).sqrt();
+    }
+    #[test]
+    #[should_panic(expected = "encountered NaN unexpectedly")]
+    fn assert_real_nan() {
+        let x = Dual::<Real<f64>>::variable(f64::INFINITY);
+        let _ = x - x;
+    }
+
+    #[test]
+    fn assert_errors() {
+        assert_eq!(var!(0.0).try_ln(), Err(InfiniteError));
+        assert_eq!(var!(1.0).try_asin(), Err(InfiniteError));
+        assert_eq!(konst!(1.0).try_div(konst!(0.0)), Err(InfiniteError));
+        let x = Dual::<Real<f64>>::variable(0.0);
+        assert_eq!(x.try_div(x), Err(NanError));
+    }
+
+    #[test]
+    fn assert_ops() {
+        let x = var!(3.0);
+        assert_dual!(x + konst!(2.0), 5.0, 1.0);
+        assert_dual!(konst!(2.0) - x, -1.0, -1.0);
+        assert_dual!(x * x, 9.0, 6.0);
+        assert_dual!(konst!(1.0) / x, 1.0 / 3.0, -1.0 / 9.0);
+        assert_dual!(x.recip(), 1.0 / 3.0, -1.0 / 9.0);
+        assert_dual!(-x, -3.0, -1.0);
+        assert_dual!((-x).abs(), 3.0, 1.0);
+    }
+
+    #[test]
+    fn assert_funcs() {
+        let x = var!(2.0);
+        assert_dual!(x.powf(3.0), 8.0, 12.0);
+        assert_dual!(x.sqrt(), 2f64.sqrt(), 0.5 / 2f64.sqrt());
+        assert_dual!(var!(8.0).cbrt(), 2.0, 1.0 / 12.0);
+        assert_dual!(x.exp(), 2f64.exp(), 2f64.exp());
+        assert_dual!(x.exp2(), 4.0, 4.0 * 2f64.ln());
+        assert_dual!(x.exp_m1(), 2f64.exp_m1(), 2f64.exp());
+        assert_dual!(x.ln(), 2f64.ln(), 0.5);
+        assert_dual!(x.log(3.0), 2f64.log(3.0), 1.0 / (2.0 * 3f64.ln()));
+        assert_dual!(x.log2(), 1.0, 1.0 / (2.0 * 2f64.ln()));
+        assert_dual!(x.log10(), 2f64.log10(), 1.0 / (2.0 * 10f64.ln()));
+        assert_dual!(x.ln_1p(), 3f64.ln(), 1.0 / 3.0);
+
+        assert_dual!(x.sin(), 2f64.sin(), 2f64.cos());
+        assert_dual!(x.cos(), 2f64.cos(), -2f64.sin());
+        let (sin, cos) = x.sin_cos();
+        assert_eq!((sin, cos), (x.sin(), x.cos()));
+        assert_dual!(x.tan(), 2f64.tan(), 1.0 / 2f64.cos().powi(2));
+        let y = var!(0.5);
+        assert_dual!(y.asin(), 0.5f64.asin(), 1.0 / 0.75f64.sqrt());
+        assert_dual!(y.acos(), 0.5f64.acos(), -1.0 / 0.75f64.sqrt());
+        assert_dual!(x.atan(), 2f64.atan(), 0.2);
+
+        // d/dx atan2(x, 1) = 1 / (1 + x^2), and d/dx hypot(x, 4) = x / hypot(x, 4).
+        assert_dual!(x.atan2(konst!(1.0)), 2f64.atan(), 0.2);
+        assert_dual!(x.hypot(konst!(4.0)), 20f64.sqrt(), 2.0 / 20f64.sqrt());
+    }
+
+    #[test]
+    fn assert_chain_rule() {
+        // f(x) = sin(x^2) * exp(x), f'(x) = (2x cos(x^2) + sin(x^2)) * exp(x).
+        let x = var!(1.5);
+        let f = (x * x).sin() * x.exp();
+        let expected = (3.0 * 2.25f64.cos() + 2.25f64.sin()) * 1.5f64.exp();
+        assert_dual!(f, 2.25f64.sin() * 1.5f64.exp(), expected);
+    }
+}
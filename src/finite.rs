@@ -1,7 +1,7 @@
-use crate::IntoInner;
+use crate::{constraint, Checked, FiniteNonNeg, IsNan, IsNegative, NonNeg, Real};
 
 /// The error produced when infinity or NaN is encountered.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InfiniteError;
 impl std::fmt::Display for InfiniteError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -14,53 +14,17 @@ pub trait IsFinite: Sized + Copy {
     fn is_finite(self) -> bool;
 }
 
-#[derive(Debug, Clone, Copy, Default)]
-#[repr(transparent)]
-pub struct Finite<F: IsFinite>(F);
-
-impl<F: IsFinite> Finite<F> {
-    /// Attempts to create a new `Finite` float.
-    /// # Errors
-    /// If the value is non-finite.
-    pub fn try_new(val: F) -> Result<Self, InfiniteError> {
-        if val.is_finite() {
-            Ok(Self(val))
-        } else {
-            Err(InfiniteError)
-        }
-    }
-    /// Const-safe constructor for `Finite` that never checks the value.
-    /// # Safety
-    /// Ensure that the value can never be infinity or `NaN`.
-    pub const unsafe fn unchecked(val: F) -> Self {
-        union Transmute<F: IsFinite> {
-            val: F,
-            finite: Finite<F>,
-        }
-
-        // SAFETY: `Real` is `repr(transparent)`.
-        Transmute { val }.finite
-    }
-    /// Gets the inner value of this number.
-    #[inline]
-    pub const fn val(self) -> F {
-        self.0
-    }
-}
-
-ctor_impls!(Finite<F: IsFinite>, "If the number is non-finite.");
+/// A floating point number that is never infinite or NaN.
+pub type Finite<F> = Checked<F, constraint::FiniteC>;
 
-impl<F: IsFinite> IntoInner<F> for Finite<F> {
-    #[inline]
-    fn into_inner(self) -> F {
-        self.val()
+impl<F: IsFinite + Default> Default for Finite<F> {
+    fn default() -> Self {
+        Self::new(F::default())
     }
 }
 
-eq_impls!(Finite<F: IsFinite>);
-ord_impls!(Finite<F: IsFinite>);
 round_impls!(Finite<F: IsFinite>);
-signed_impls!(Finite<F: IsFinite>);
+signed_impls!(Finite<F: IsFinite>, abs -> FiniteNonNeg<F: IsNegative>);
 sum_impls!(
     Finite<F: IsFinite>,
     InfiniteError,
@@ -77,17 +41,47 @@ product_impls!(
     "If the result is non-finite."
 );
 impl<F: IsFinite + crate::ops::Pow> Finite<F> {
-    pow_methods!(F, InfiniteError, "If the result is non-finite.");
+    pow_methods!(
+        F,
+        InfiniteError,
+        "If the result is non-finite.",
+        -> FiniteNonNeg<F: IsNegative>
+    );
     recip_methods!(F, InfiniteError, "If the result is non-finite.");
-    sqrt_methods!(F, InfiniteError, "If the result is non-finite.");
+    sqrt_methods!(
+        F,
+        InfiniteError,
+        "If the result is non-finite.",
+        -> FiniteNonNeg<F: IsNegative>
+    );
     cbrt_methods!(F);
-    hypot_methods!(F, InfiniteError, "If the result is non-finite.");
+    hypot_methods!(
+        F,
+        InfiniteError,
+        "If the result is non-finite.",
+        -> FiniteNonNeg<F: IsNegative>
+    );
 }
 exp_impls!(
     Finite<F: IsFinite>,
     InfiniteError,
-    "If the result is non-finite."
+    "If the result is non-finite.",
+    -> FiniteNonNeg<F: IsNegative>
 );
+impl<F: IsFinite + IsNegative + crate::ops::Exp> Finite<F> {
+    /// Computes `e^(self)`, which may overflow to infinity.
+    ///
+    /// Unlike [`Finite::exp`], this can never fail, since the result is never negative or NaN.
+    #[must_use]
+    pub fn exp_non_neg(self) -> NonNeg<F> {
+        unsafe { NonNeg::unchecked(self.val().exp()) }
+    }
+    /// Computes `2^(self)`, which may overflow to infinity.
+    #[must_use]
+    pub fn exp2_non_neg(self) -> NonNeg<F> {
+        unsafe { NonNeg::unchecked(self.val().exp2()) }
+    }
+}
 impl<F: IsFinite + crate::ops::Trig> Finite<F> {
     sin_cos_methods!(F); // sin and cos always succeed for finite values.
     tan_methods!(F, InfiniteError, "If the result is non-finite.");
@@ -100,6 +94,55 @@ impl<F: IsFinite + crate::ops::Trig> Finite<F> {
     atan2_methods!(F, InfiniteError, "If the result is non-finite.");
 }
 
+// Arithmetic on two finite numbers can overflow to infinity, but can never produce NaN.
+// The same goes for adding a finite number to an infinite one.
+impl<F: IsFinite + IsNan> Finite<F> {
+    /// Adds a finite and a real number.
+    #[must_use]
+    pub fn add_real(self, rhs: impl Into<Real<F>>) -> Real<F>
+    where
+        F: ::core::ops::Add<Output = F>,
+    {
+        unsafe { Real::unchecked(self.val() + rhs.into().val()) }
+    }
+    /// Subtracts a real number from a finite number.
+    #[must_use]
+    pub fn sub_real(self, rhs: impl Into<Real<F>>) -> Real<F>
+    where
+        F: ::core::ops::Sub<Output = F>,
+    {
+        unsafe { Real::unchecked(self.val() - rhs.into().val()) }
+    }
+    /// Multiplies two finite numbers, where the product may overflow to infinity.
+    #[must_use]
+    pub fn mul_real(self, rhs: impl Into<Self>) -> Real<F>
+    where
+        F: ::core::ops::Mul<Output = F>,
+    {
+        unsafe { Real::unchecked(self.val() * rhs.into().val()) }
+    }
+}
+impl<F: IsFinite + IsNan> Real<F> {
+    /// Adds a real and a finite number.
+    #[must_use]
+    pub fn add_finite(self, rhs: impl Into<Finite<F>>) -> Self
+    where
+        F: ::core::ops::Add<Output = F>,
+    {
+        unsafe { Self::unchecked(self.val() + rhs.into().val()) }
+    }
+    /// Subtracts a finite number from a real number.
+    #[must_use]
+    pub fn sub_finite(self, rhs: impl Into<Finite<F>>) -> Self
+    where
+        F: ::core::ops::Sub<Output = F>,
+    {
+        unsafe { Self::unchecked(self.val() - rhs.into().val()) }
+    }
+}
+
+convert_impls!(Finite<F: IsFinite>, InfiniteError => Real<F: IsNan>);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,22 +154,22 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
     fn assert_new_nan() {
         finite!(f32::NAN);
     }
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
     fn assert_new_nan2() {
         finite!(-f32::NAN);
     }
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
     fn assert_new_inf() {
         finite!(f32::INFINITY);
     }
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
     fn assert_new_inf2() {
         finite!(f32::NEG_INFINITY);
     }
@@ -168,7 +211,7 @@ mod tests {
 
     #[test]
     #[allow(clippy::bool_assert_comparison)]
-    #[allow(clippy::cmp_nan)]
+    #[allow(invalid_nan_comparisons)]
     fn assert_cmp_weird() {
         assert!(finite!(-1.0f32) < finite!(0.0));
 
@@ -204,6 +247,23 @@ mod tests {
         assert_epsilon!(finite!(147.413_16f32).ln_1p(), finite!(5.0));
     }
 
+    #[test]
+    fn assert_non_neg_output() {
+        let abs: FiniteNonNeg<f32> = finite!(-2.0f32).abs();
+        assert_eq!(abs, FiniteNonNeg::new(2.0));
+        let sqrt: FiniteNonNeg<f32> = finite!(4.0f32).sqrt();
+        assert_eq!(sqrt, FiniteNonNeg::new(2.0));
+        let exp: FiniteNonNeg<f32> = finite!(0.0f32).exp2();
+        assert_eq!(exp, FiniteNonNeg::new(1.0));
+        let square: FiniteNonNeg<f32> = finite!(-3.0f32).square_powi(1);
+        assert_eq!(square, FiniteNonNeg::new(9.0));
+        assert_err!(finite!(f32::MAX).try_square_powi(1));
+
+        assert_eq!(finite!(1000.0f32).exp_non_neg(), NonNeg::new(f32::INFINITY));
+        assert_eq!(finite!(-1000.0f32).exp_non_neg(), NonNeg::new(0.0));
+        assert_eq!(finite!(3.0f32).exp2_non_neg(), NonNeg::new(8.0));
+    }
+
     #[test]
     fn assert_trig() {
         use std::f32::consts::{FRAC_1_SQRT_2, PI};
@@ -232,4 +292,32 @@ mod tests {
         assert_epsilon!(finite!(1.0f32).atan(), finite!(PI / 4.0));
         // inf.atan() = infinity, can't show here
     }
+
+    #[test]
+    fn assert_real_output() {
+        assert_eq!(
+            finite!(f32::MAX).add_real(finite!(f32::MAX)),
+            Real::new(f32::INFINITY)
+        );
+        assert_eq!(
+            finite!(f32::MIN).sub_real(finite!(f32::MAX)),
+            Real::new(f32::NEG_INFINITY)
+        );
+        assert_eq!(
+            finite!(f32::MAX).mul_real(finite!(-2.0)),
+            Real::new(f32::NEG_INFINITY)
+        );
+        assert_eq!(finite!(2.0f32).mul_real(finite!(3.0)), Real::new(6.0));
+
+        // mixed pairs
+        let inf = Real::new(f32::INFINITY);
+        assert_eq!(finite!(1.0f32).add_real(inf), inf);
+        assert_eq!(finite!(1.0f32).sub_real(inf), Real::new(f32::NEG_INFINITY));
+        assert_eq!(inf.add_finite(finite!(f32::MIN)), inf);
+        assert_eq!(inf.sub_finite(FiniteNonNeg::new(1.0)), inf);
+        assert_eq!(
+            finite!(-2.0f32).mul_real(FiniteNonNeg::new(3.0)),
+            Real::new(-6.0)
+        );
+    }
 }
@@ -0,0 +1,218 @@
+use crate::{Finite, NonNeg};
+
+/// The error produced when an integer cannot be represented exactly as a float.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InexactError;
+impl std::fmt::Display for InexactError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "encountered an integer that cannot be represented exactly"
+        )
+    }
+}
+
+/// The error produced when a float is out of range for an integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRangeError;
+impl std::fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "encountered a value out of range for the integer type")
+    }
+}
+
+/// How to round a float to an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds towards zero, like `as`.
+    Trunc,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+    /// Rounds to the nearest integer, with ties going to the even integer.
+    HalfEven,
+}
+
+macro_rules! round_raw {
+    ($val: expr, $mode: expr) => {
+        match $mode {
+            Rounding::Trunc => $val.trunc(),
+            Rounding::Floor => $val.floor(),
+            Rounding::Ceil => $val.ceil(),
+            Rounding::HalfEven => $val.round_ties_even(),
+        }
+    };
+}
+
+macro_rules! int_impls {
+    ($f: ty, lossless: [$($lossless: ty),*], exact: [$($exact: ty),*]) => {
+        $(
+            impl From<$lossless> for Finite<$f> {
+                #[inline]
+                fn from(val: $lossless) -> Self {
+                    unsafe { Self::unchecked(<$f>::from(val)) }
+                }
+            }
+        )*
+        $(
+            impl TryFrom<$exact> for Finite<$f> {
+                type Error = InexactError;
+                #[allow(
+                    clippy::cast_precision_loss,
+                    clippy::cast_possible_truncation,
+                    clippy::cast_lossless
+                )]
+                fn try_from(val: $exact) -> Result<Self, InexactError> {
+                    let float = val as $f;
+                    // `i128` can hold every value of `$exact` along with the next power of two,
+                    // which is where the float lands if it rounds past the maximum.
+                    if float as i128 == val as i128 {
+                        Ok(unsafe { Self::unchecked(float) })
+                    } else {
+                        Err(InexactError)
+                    }
+                }
+            }
+        )*
+
+        impl Finite<$f> {
+            /// Rounds this number, then attempts to convert it to an `i32`.
+            /// # Errors
+            /// If the rounded value is out of range.
+            pub fn try_to_i32(self, mode: Rounding) -> Result<i32, OutOfRangeError> {
+                self.try_to_int(mode)
+            }
+            /// Rounds this number, then attempts to convert it to an `i64`.
+            /// # Errors
+            /// If the rounded value is out of range.
+            pub fn try_to_i64(self, mode: Rounding) -> Result<i64, OutOfRangeError> {
+                self.try_to_int(mode)
+            }
+            /// Rounds this number, then attempts to convert it to a `u32`.
+            /// # Errors
+            /// If the rounded value is negative or out of range.
+            pub fn try_to_u32(self, mode: Rounding) -> Result<u32, OutOfRangeError> {
+                self.try_to_int(mode)
+            }
+            /// Rounds this number, then attempts to convert it to a `u64`.
+            /// # Errors
+            /// If the rounded value is negative or out of range.
+            pub fn try_to_u64(self, mode: Rounding) -> Result<u64, OutOfRangeError> {
+                self.try_to_int(mode)
+            }
+            /// Rounds this number, then attempts to convert it to a `usize`.
+            /// # Errors
+            /// If the rounded value is negative or out of range.
+            pub fn try_to_usize(self, mode: Rounding) -> Result<usize, OutOfRangeError> {
+                self.try_to_int(mode)
+            }
+            #[allow(clippy::cast_possible_truncation)]
+            fn try_to_int<I: TryFrom<i128>>(self, mode: Rounding) -> Result<I, OutOfRangeError> {
+                // the rounded value is a whole number, so this is exact unless it saturates,
+                // in which case it's out of range for every target type anyway.
+                let val = round_raw!(self.val(), mode) as i128;
+                I::try_from(val).map_err(|_| OutOfRangeError)
+            }
+        }
+
+        impl NonNeg<$f> {
+            /// Rounds this number, then attempts to convert it to a `u32`.
+            /// # Errors
+            /// If the rounded value is out of range, including if it is infinite.
+            pub fn try_to_u32(self, mode: Rounding) -> Result<u32, OutOfRangeError> {
+                self.try_to_uint(mode)
+            }
+            /// Rounds this number, then attempts to convert it to a `u64`.
+            /// # Errors
+            /// If the rounded value is out of range, including if it is infinite.
+            pub fn try_to_u64(self, mode: Rounding) -> Result<u64, OutOfRangeError> {
+                self.try_to_uint(mode)
+            }
+            /// Rounds this number, then attempts to convert it to a `usize`.
+            /// # Errors
+            /// If the rounded value is out of range, including if it is infinite.
+            pub fn try_to_usize(self, mode: Rounding) -> Result<usize, OutOfRangeError> {
+                self.try_to_uint(mode)
+            }
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn try_to_uint<I: TryFrom<u128>>(self, mode: Rounding) -> Result<I, OutOfRangeError> {
+                // the sign is known to be positive, so only the upper bound needs to be checked.
+                // infinity saturates to `u128::MAX`, which is out of range for every target type.
+                let val = round_raw!(self.val(), mode) as u128;
+                I::try_from(val).map_err(|_| OutOfRangeError)
+            }
+        }
+    };
+}
+int_impls!(f32, lossless: [i8, u8, i16, u16], exact: [i32, u32, i64, u64, isize, usize]);
+int_impls!(f64, lossless: [i8, u8, i16, u16, i32, u32], exact: [i64, u64, isize, usize]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! finite {
+        ($f: expr) => {
+            Finite::new($f)
+        };
+    }
+
+    #[test]
+    fn assert_from_int() {
+        assert_eq!(Finite::<f64>::from(i32::MIN), finite!(-2_147_483_648.0));
+        assert_eq!(Finite::<f32>::from(u16::MAX), finite!(65535.0));
+
+        assert_eq!(
+            Finite::<f64>::try_from(1i64 << 53),
+            Ok(finite!(9_007_199_254_740_992.0))
+        );
+        assert_eq!(Finite::<f64>::try_from((1i64 << 53) + 1), Err(InexactError));
+        assert_eq!(Finite::<f64>::try_from(u64::MAX), Err(InexactError));
+        assert_eq!(Finite::<f64>::try_from(i64::MAX), Err(InexactError));
+        assert_eq!(
+            Finite::<f64>::try_from(i64::MIN),
+            Ok(finite!(-(2.0f64.powi(63))))
+        );
+        assert_eq!(Finite::<f32>::try_from(16_777_217i32), Err(InexactError));
+    }
+
+    #[test]
+    fn assert_to_int() {
+        use Rounding::{Ceil, Floor, HalfEven, Trunc};
+
+        assert_eq!(finite!(-2.5f64).try_to_i32(Trunc), Ok(-2));
+        assert_eq!(finite!(-2.5f64).try_to_i32(Floor), Ok(-3));
+        assert_eq!(finite!(-2.5f64).try_to_i32(Ceil), Ok(-2));
+        assert_eq!(finite!(-2.5f64).try_to_i32(HalfEven), Ok(-2));
+        assert_eq!(finite!(3.5f64).try_to_i32(HalfEven), Ok(4));
+
+        assert_eq!(finite!(2_147_483_647.0f64).try_to_i32(Trunc), Ok(i32::MAX));
+        assert_eq!(
+            finite!(2_147_483_648.0f64).try_to_i32(Trunc),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(finite!(-1.0f32).try_to_u64(Trunc), Err(OutOfRangeError));
+        assert_eq!(finite!(-0.5f32).try_to_u64(Trunc), Ok(0));
+        assert_eq!(finite!(f32::MAX).try_to_i64(Trunc), Err(OutOfRangeError));
+        assert_eq!(
+            finite!(1e19f64).try_to_u64(Floor),
+            Ok(10_000_000_000_000_000_000)
+        );
+        assert_eq!(finite!(7.9f32).try_to_usize(Trunc), Ok(7));
+    }
+
+    #[test]
+    fn assert_non_neg_to_int() {
+        assert_eq!(NonNeg::new(2.5f32).try_to_u32(Rounding::HalfEven), Ok(2));
+        assert_eq!(
+            NonNeg::new(4_294_967_296.0f64).try_to_u32(Rounding::Trunc),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(
+            NonNeg::new(f64::INFINITY).try_to_u64(Rounding::Floor),
+            Err(OutOfRangeError)
+        );
+        assert_eq!(NonNeg::new(0.0f64).try_to_usize(Rounding::Ceil), Ok(0));
+    }
+}
@@ -0,0 +1,561 @@
+use crate::{
+    ops::{Angle, Consts, Exp, NextFloat, Pow, Round, Signed, Trig},
+    IntoInner, IsNan, NonNeg, Real, ToOrd,
+};
+
+/// The error produced by an invalid [`Interval`] or interval operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalError {
+    /// One of the endpoints is NaN.
+    Nan,
+    /// The lower endpoint is greater than the upper endpoint.
+    Inverted,
+    /// Attempted to divide by an interval that contains zero.
+    ContainsZero,
+    /// The entire interval is outside the domain of the operation, such as the square root
+    /// of an interval that is entirely negative.
+    Domain,
+}
+impl std::fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Nan => write!(f, "encountered NaN unexpectedly"),
+            Self::Inverted => write!(
+                f,
+                "encountered an interval whose lower bound exceeds its upper bound"
+            ),
+            Self::ContainsZero => write!(f, "attempted to divide by an interval containing zero"),
+            Self::Domain => write!(
+                f,
+                "encountered an interval entirely outside the domain of the operation"
+            ),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait IntervalFloat:
+    IsNan
+    + Consts
+    + ToOrd
+    + PartialOrd
+    + Default
+    + NextFloat
+    + Round
+    + Signed
+    + Pow
+    + Exp
+    + Trig
+    + Angle
+    + ::core::ops::Add<Output = Self>
+    + ::core::ops::Sub<Output = Self>
+    + ::core::ops::Mul<Output = Self>
+    + ::core::ops::Div<Output = Self>
+    + ::core::ops::Neg<Output = Self>
+{
+}
+impl<T> IntervalFloat for T where
+    T: IsNan
+        + Consts
+        + ToOrd
+        + PartialOrd
+        + Default
+        + NextFloat
+        + Round
+        + Signed
+        + Pow
+        + Exp
+        + Trig
+        + Angle
+        + ::core::ops::Add<Output = Self>
+        + ::core::ops::Sub<Output = Self>
+        + ::core::ops::Mul<Output = Self>
+        + ::core::ops::Div<Output = Self>
+        + ::core::ops::Neg<Output = Self>
+{
+}
+
+/// A closed interval `[lo, hi]` of real numbers, which is guaranteed to contain the exact result
+/// of every operation performed on it.
+///
+/// After each operation, the endpoints are rounded outwards by one step,
+/// which bounds any rounding error introduced by the operation.
+#[derive(Debug, Clone, Copy)]
+pub struct Interval<F: IsNan> {
+    lo: Real<F>,
+    hi: Real<F>,
+}
+
+impl<F: IsNan + ToOrd> Interval<F> {
+    /// Attempts to create a new interval.
+    /// # Errors
+    /// If either endpoint is NaN, or if `lo > hi`.
+    pub fn try_new(lo: impl IntoInner<F>, hi: impl IntoInner<F>) -> Result<Self, IntervalError> {
+        let lo = Real::try_new(lo.into_inner()).map_err(|_| IntervalError::Nan)?;
+        let hi = Real::try_new(hi.into_inner()).map_err(|_| IntervalError::Nan)?;
+        if lo > hi {
+            Err(IntervalError::Inverted)
+        } else {
+            Ok(Self { lo, hi })
+        }
+    }
+    /// Creates a new interval.
+    /// # Panics
+    /// If either endpoint is NaN, or if `lo > hi`.
+    /// Note that this fn will *not* panic in release mode, unless the `strict` feature flag is set.
+    #[track_caller]
+    pub fn new(lo: impl IntoInner<F>, hi: impl IntoInner<F>) -> Self {
+        if crate::STRICT {
+            crate::unwrap_display(Self::try_new(lo, hi))
+        } else {
+            // SAFETY: lol
+            unsafe {
+                Self {
+                    lo: Real::unchecked(lo.into_inner()),
+                    hi: Real::unchecked(hi.into_inner()),
+                }
+            }
+        }
+    }
+    /// Creates an interval that contains a single number.
+    pub fn point(val: Real<F>) -> Self {
+        Self { lo: val, hi: val }
+    }
+    /// Gets the lower endpoint of this interval.
+    #[inline]
+    pub fn lo(self) -> Real<F> {
+        self.lo
+    }
+    /// Gets the upper endpoint of this interval.
+    #[inline]
+    pub fn hi(self) -> Real<F> {
+        self.hi
+    }
+
+    /// Returns true if this interval contains `val`.
+    pub fn contains(self, val: impl IntoInner<F>) -> bool {
+        let val = val.into_inner();
+        !val.is_nan() && self.lo <= val && self.hi >= val
+    }
+    /// Finds the overlap between two intervals, if they overlap at all.
+    #[must_use]
+    pub fn intersect(self, other: Self) -> Option<Self> {
+        let lo = Ord::max(self.lo, other.lo);
+        let hi = Ord::min(self.hi, other.hi);
+        if lo > hi {
+            None
+        } else {
+            Some(Self { lo, hi })
+        }
+    }
+    /// Finds the smallest interval that contains both intervals.
+    #[must_use]
+    pub fn hull(self, other: Self) -> Self {
+        let lo = Ord::min(self.lo, other.lo);
+        let hi = Ord::max(self.hi, other.hi);
+        Self { lo, hi }
+    }
+}
+
+fn min<F: ToOrd>(a: F, b: F) -> F {
+    if a.to_ord() <= b.to_ord() {
+        a
+    } else {
+        b
+    }
+}
+fn max<F: ToOrd>(a: F, b: F) -> F {
+    if a.to_ord() >= b.to_ord() {
+        a
+    } else {
+        b
+    }
+}
+/// Multiplies two numbers, treating `0 * inf` as zero, which is the convention for intervals.
+fn mul<F: IntervalFloat>(a: F, b: F) -> F {
+    let val = a * b;
+    if val.is_nan() {
+        F::default()
+    } else {
+        val
+    }
+}
+/// Divides two numbers, returning the least and greatest limits of the quotient.
+/// `inf / inf` may approach any number of the right sign, so it spans zero to infinity.
+fn div<F: IntervalFloat>(a: F, b: F) -> (F, F) {
+    let val = a / b;
+    if !val.is_nan() {
+        return (val, val);
+    }
+    let (zero, inf) = (F::default(), F::one() / F::default());
+    if a.is_sign_negative() == b.is_sign_negative() {
+        (zero, inf)
+    } else {
+        (-inf, -zero)
+    }
+}
+/// Returns true if `[a, b]` contains any number congruent to `phase` modulo `tau`.
+fn contains_phase<F: IntervalFloat>(a: F, b: F, phase: F, tau: F) -> bool {
+    // widen the interval, so that rounding errors can only cause false positives.
+    let (a, b) = (a.next_down(), b.next_up());
+    let k = ((a - phase) / tau).ceil();
+    phase + k * tau <= b
+}
+
+impl<F: IntervalFloat> Interval<F> {
+    /// Rounds the endpoints outwards, and checks the result.
+    fn try_outward(lo: F, hi: F) -> Result<Self, IntervalError> {
+        Self::try_new(lo.next_down(), hi.next_up())
+    }
+
+    /// Computes the width of this interval, rounded up.
+    #[must_use]
+    pub fn width(self) -> NonNeg<F> {
+        let (lo, hi) = (self.lo.val(), self.hi.val());
+        if lo == hi {
+            // this avoids computing `inf - inf`.
+            NonNeg::new(F::default())
+        } else {
+            NonNeg::new((hi - lo).next_up())
+        }
+    }
+    /// Computes the number halfway between the endpoints.
+    ///
+    /// If the interval is unbounded in both directions, this returns zero.
+    #[must_use]
+    pub fn midpoint(self) -> Real<F> {
+        let (lo, hi) = (self.lo.val(), self.hi.val());
+        let half = F::one() / (F::one() + F::one());
+        // halving first avoids overflowing.
+        let mid = lo * half + hi * half;
+        if mid.is_nan() {
+            Real::new(F::default())
+        } else {
+            // halving may underflow for subnormal endpoints, which could leave the interval.
+            Real::new(min(max(mid, lo), hi))
+        }
+    }
+
+    /// Attempts to add two intervals.
+    /// # Errors
+    /// If the result has NaN endpoints (caused by adding infinities of opposite signs).
+    pub fn try_add(self, rhs: Self) -> Result<Self, IntervalError> {
+        Self::try_outward(self.lo.val() + rhs.lo.val(), self.hi.val() + rhs.hi.val())
+    }
+    /// Attempts to subtract two intervals.
+    /// # Errors
+    /// If the result has NaN endpoints (caused by subtracting infinities of the same sign).
+    pub fn try_sub(self, rhs: Self) -> Result<Self, IntervalError> {
+        Self::try_outward(self.lo.val() - rhs.hi.val(), self.hi.val() - rhs.lo.val())
+    }
+    /// Attempts to multiply two intervals.
+    /// # Errors
+    /// This should never fail, but the result is still checked.
+    pub fn try_mul(self, rhs: Self) -> Result<Self, IntervalError> {
+        let (a, b) = (self.lo.val(), self.hi.val());
+        let (c, d) = (rhs.lo.val(), rhs.hi.val());
+        let (ac, ad, bc, bd) = (mul(a, c), mul(a, d), mul(b, c), mul(b, d));
+        Self::try_outward(min(min(ac, ad), min(bc, bd)), max(max(ac, ad), max(bc, bd)))
+    }
+    /// Attempts to divide two intervals.
+    /// # Errors
+    /// If `rhs` contains zero.
+    pub fn try_div(self, rhs: Self) -> Result<Self, IntervalError> {
+        if rhs.contains(F::default()) {
+            return Err(IntervalError::ContainsZero);
+        }
+        let (a, b) = (self.lo.val(), self.hi.val());
+        let (c, d) = (rhs.lo.val(), rhs.hi.val());
+        let (ac, ad, bc, bd) = (div(a, c), div(a, d), div(b, c), div(b, d));
+        Self::try_outward(
+            min(min(ac.0, ad.0), min(bc.0, bd.0)),
+            max(max(ac.1, ad.1), max(bc.1, bd.1)),
+        )
+    }
+
+    /// Attempts to find the square root of each number in this interval.
+    /// Any negative part of the interval is ignored.
+    /// # Errors
+    /// If the entire interval is negative.
+    pub fn try_sqrt(self) -> Result<Self, IntervalError> {
+        let zero = F::default();
+        if self.hi.val() < zero {
+            return Err(IntervalError::Domain);
+        }
+        let lo = max(self.lo.val(), zero).sqrt().next_down();
+        Self::try_new(max(lo, zero), self.hi.val().sqrt().next_up())
+    }
+    /// Finds the square root of each number in this interval.
+    /// Any negative part of the interval is ignored.
+    /// # Panics
+    /// If the entire interval is negative.
+    #[track_caller]
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        crate::unwrap_display(self.try_sqrt())
+    }
+    /// Attempts to find the natural logarithm of each number in this interval.
+    /// Any negative part of the interval is ignored.
+    /// # Errors
+    /// If the entire interval is negative.
+    pub fn try_ln(self) -> Result<Self, IntervalError> {
+        let zero = F::default();
+        if self.hi.val() < zero {
+            return Err(IntervalError::Domain);
+        }
+        Self::try_outward(max(self.lo.val(), zero).ln(), self.hi.val().ln())
+    }
+    /// Finds the natural logarithm of each number in this interval.
+    /// Any negative part of the interval is ignored.
+    /// # Panics
+    /// If the entire interval is negative.
+    #[track_caller]
+    #[must_use]
+    pub fn ln(self) -> Self {
+        crate::unwrap_display(self.try_ln())
+    }
+    /// Finds `e^x` for each number `x` in this interval.
+    #[must_use]
+    pub fn exp(self) -> Self {
+        let lo = max(self.lo.val().exp().next_down(), F::default());
+        let hi = self.hi.val().exp().next_up();
+        // exp is monotonic and never produces NaN for non-NaN inputs.
+        unsafe {
+            Self {
+                lo: Real::unchecked(lo),
+                hi: Real::unchecked(hi),
+            }
+        }
+    }
+
+    /// Computes the range of `f` over this interval,
+    /// where `f` is a sinusoid that peaks at `peak` and bottoms out at `trough`.
+    fn sinusoid(self, f: fn(F) -> F, peak: F, trough: F) -> Self {
+        let (a, b) = (self.lo.val(), self.hi.val());
+        let one = F::one();
+        let quarter = F::frac_pi_2();
+        let tau = (quarter + quarter) + (quarter + quarter);
+
+        // if the interval covers an entire turn, the result covers the entire range.
+        // this also catches infinite endpoints, and endpoints so large that
+        // adjacent floats are more than a quarter turn apart.
+        let (lo, hi) = if (b - a).is_nan()
+            || b - a >= tau
+            || a.next_up() - a > quarter
+            || b - b.next_down() > quarter
+        {
+            (-one, one)
+        } else {
+            let (fa, fb) = (f(a), f(b));
+            let mut lo = max(min(fa, fb).next_down(), -one);
+            let mut hi = min(max(fa, fb).next_up(), one);
+            if contains_phase(a, b, peak, tau) {
+                hi = one;
+            }
+            if contains_phase(a, b, trough, tau) {
+                lo = -one;
+            }
+            (lo, hi)
+        };
+        unsafe {
+            Self {
+                lo: Real::unchecked(lo),
+                hi: Real::unchecked(hi),
+            }
+        }
+    }
+    /// Finds the sine of each number in this interval.
+    #[must_use]
+    pub fn sin(self) -> Self {
+        let quarter = F::frac_pi_2();
+        self.sinusoid(F::sin, quarter, -quarter)
+    }
+    /// Finds the cosine of each number in this interval.
+    #[must_use]
+    pub fn cos(self) -> Self {
+        let quarter = F::frac_pi_2();
+        self.sinusoid(F::cos, F::default(), quarter + quarter)
+    }
+}
+
+impl<F: IsNan + ToOrd> PartialEq for Interval<F> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.lo == rhs.lo && self.hi == rhs.hi
+    }
+}
+impl<F: IsNan + ToOrd> Eq for Interval<F> {}
+
+impl<F: IntervalFloat> ::core::ops::Add for Interval<F> {
+    type Output = Self;
+    #[track_caller]
+    fn add(self, rhs: Self) -> Self {
+        crate::unwrap_display(self.try_add(rhs))
+    }
+}
+impl<F: IntervalFloat> ::core::ops::Sub for Interval<F> {
+    type Output = Self;
+    #[track_caller]
+    fn sub(self, rhs: Self) -> Self {
+        crate::unwrap_display(self.try_sub(rhs))
+    }
+}
+impl<F: IntervalFloat> ::core::ops::Mul for Interval<F> {
+    type Output = Self;
+    #[track_caller]
+    fn mul(self, rhs: Self) -> Self {
+        crate::unwrap_display(self.try_mul(rhs))
+    }
+}
+impl<F: IntervalFloat> ::core::ops::Div for Interval<F> {
+    type Output = Self;
+    #[track_caller]
+    fn div(self, rhs: Self) -> Self {
+        crate::unwrap_display(self.try_div(rhs))
+    }
+}
+impl<F: IsNan + ::core::ops::Neg<Output = F>> ::core::ops::Neg for Interval<F> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        // negation is exact, so there's no need to round.
+        unsafe {
+            Self {
+                lo: Real::unchecked(-self.hi.val()),
+                hi: Real::unchecked(-self.lo.val()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! iv {
+        ($lo: expr, $hi: expr) => {
+            Interval::new($lo, $hi)
+        };
+    }
+    /// Asserts that `$i` contains `$val`, and is not much wider than it needs to be.
+    macro_rules! assert_tight {
+        ($i: expr, $lo: expr, $hi: expr) => {{
+            let i = $i;
+            assert!(i.lo() <= $lo && i.hi() >= $hi, "{i:?} is too narrow");
+            assert_epsilon!(i.lo(), Real::new($lo), 1e-5);
+            assert_epsilon!(i.hi(), Real::new($hi), 1e-5);
+        }};
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered an interval whose lower bound exceeds its upper bound")]
+    fn assert_new_inverted() {
+        iv!(1.0f32, 0.0);
+    }
+    #[test]
+    #[should_panic(expected = "encountered NaN unexpectedly")]
+    fn assert_new_nan() {
+        iv!(f32::NAN, 0.0);
+    }
+    #[test]
+    #[should_panic(expected = "attempted to divide by an interval containing zero")]
+    fn assert_div_zero() {
+        let _ = iv!(1.0f32, 2.0) / iv!(-1.0, 1.0);
+    }
+
+    #[test]
+    fn assert_errors() {
+        assert_eq!(
+            iv!(1.0f32, 2.0).try_div(iv!(-0.0, 1.0)),
+            Err(IntervalError::ContainsZero)
+        );
+        assert_eq!(
+            iv!(f32::NEG_INFINITY, 0.0).try_add(iv!(f32::INFINITY, f32::INFINITY)),
+            Err(IntervalError::Nan)
+        );
+        assert_eq!(iv!(-2.0f32, -1.0).try_sqrt(), Err(IntervalError::Domain));
+        assert_eq!(iv!(-2.0f32, -1.0).try_ln(), Err(IntervalError::Domain));
+    }
+
+    #[test]
+    fn assert_ops() {
+        // 0.1 and 0.2 are not exact, but the result must still contain 0.3.
+        let sum = Interval::point(Real::new(0.1f32)) + Interval::point(Real::new(0.2));
+        assert!(sum.contains(0.3));
+        assert!(sum.contains(0.1f32 + 0.2));
+
+        assert_tight!(iv!(1.0f32, 2.0) + iv!(3.0, 4.0), 4.0, 6.0);
+        assert_tight!(iv!(1.0f32, 2.0) - iv!(3.0, 4.0), -3.0, -1.0);
+        assert_tight!(iv!(-1.0f32, 2.0) * iv!(3.0, 4.0), -4.0, 8.0);
+        assert_tight!(iv!(1.0f32, 2.0) / iv!(-4.0, -2.0), -1.0, -0.25);
+        assert_eq!(-iv!(1.0f32, 2.0), iv!(-2.0, -1.0));
+        // 0 * inf is treated as zero.
+        let prod = iv!(0.0f32, 1.0) * iv!(2.0, f32::INFINITY);
+        assert_epsilon!(prod.lo(), Real::new(0.0), 1e-5);
+        assert_eq!(prod.hi(), Real::new(f32::INFINITY));
+        // inf / inf may approach any number of the right sign.
+        let inf = f32::INFINITY;
+        let quot = iv!(1.0f32, inf) / iv!(1.0, inf);
+        assert_epsilon!(quot.lo(), Real::new(0.0), 1e-5);
+        assert_eq!(quot.hi(), Real::new(inf));
+        let quot = iv!(-inf, -1.0f32) / iv!(inf, inf);
+        assert_eq!(quot.lo(), Real::new(-inf));
+        assert_epsilon!(quot.hi(), Real::new(0.0), 1e-5);
+    }
+
+    #[test]
+    fn assert_funcs() {
+        use std::f32::consts::{E, PI};
+
+        assert_tight!(iv!(-1.0f32, 4.0).sqrt(), 0.0, 2.0);
+        assert_tight!(iv!(1.0f32, E).ln(), 0.0, 1.0);
+        assert_tight!(iv!(0.0f32, 1.0).exp(), 1.0, E);
+        assert_tight!(iv!(f32::NEG_INFINITY, 0.0).exp(), 0.0, 1.0);
+
+        assert_tight!(iv!(0.0f32, PI).sin(), 0.0, 1.0);
+        assert_tight!(iv!(PI, 2.0 * PI).sin(), -1.0, 0.0);
+        assert_tight!(iv!(0.1f32, 0.2).sin(), 0.1f32.sin(), 0.2f32.sin());
+        assert_tight!(iv!(-0.5f32, 0.5).cos(), 0.5f32.cos(), 1.0);
+        assert_tight!(iv!(3.0f32, 3.5).cos(), -1.0, 3.5f32.cos());
+        assert_eq!(iv!(0.0f32, 7.0).cos(), iv!(-1.0, 1.0));
+        assert_eq!(iv!(0.0f32, f32::INFINITY).sin(), iv!(-1.0, 1.0));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn assert_next_float() {
+        let tiny = f32::from_bits(1);
+        assert_eq!(NextFloat::next_up(0.0f32), tiny);
+        assert_eq!(NextFloat::next_up(-0.0f32), tiny);
+        assert_eq!(NextFloat::next_down(0.0f32), -tiny);
+        assert_eq!(NextFloat::next_up(-tiny), -0.0);
+        assert_eq!(NextFloat::next_up(1.0f32), 1.0 + f32::EPSILON);
+        assert_eq!(NextFloat::next_down(-1.0f32), -1.0 - f32::EPSILON);
+        assert_eq!(NextFloat::next_up(f32::MAX), f32::INFINITY);
+        assert_eq!(NextFloat::next_down(f32::INFINITY), f32::MAX);
+        assert_eq!(NextFloat::next_up(f32::INFINITY), f32::INFINITY);
+        assert_eq!(NextFloat::next_down(f32::NEG_INFINITY), f32::NEG_INFINITY);
+        assert!(NextFloat::next_up(f64::NAN).is_nan());
+        assert_eq!(NextFloat::next_down(1.0f64), 1.0 - f64::EPSILON / 2.0);
+    }
+
+    #[test]
+    fn assert_sets() {
+        let a = iv!(0.0f32, 2.0);
+        let b = iv!(1.0f32, 3.0);
+        assert!(a.contains(2.0) && !a.contains(f32::NAN) && !a.contains(2.5));
+        assert_eq!(a.intersect(b), Some(iv!(1.0, 2.0)));
+        assert_eq!(a.intersect(iv!(5.0, 6.0)), None);
+        assert_eq!(a.hull(b), iv!(0.0, 3.0));
+
+        assert!(a.width() >= NonNeg::new(2.0));
+        assert_eq!(iv!(1.0f32, 1.0).width(), NonNeg::new(0.0));
+        assert_eq!(iv!(f32::INFINITY, f32::INFINITY).width(), NonNeg::new(0.0));
+        assert_eq!(a.midpoint(), Real::new(1.0));
+        assert_eq!(iv!(-f32::MAX, f32::MAX).midpoint(), Real::new(0.0));
+        let tiny = f32::from_bits(1);
+        assert!(iv!(tiny, tiny).contains(iv!(tiny, tiny).midpoint()));
+        assert_eq!(
+            iv!(f32::NEG_INFINITY, f32::INFINITY).midpoint(),
+            Real::new(0.0)
+        );
+    }
+}
@@ -1,11 +1,33 @@
 //! # Intro
 //! This crate is for dealing checked floating point numbers.
-//! It exports three types: [`Real`], [`Finite`], and [`NonNeg`]. `Real` is checked at runtime
+//! It exports four core types: [`Real`], [`Finite`], [`NonNeg`] and [`Positive`]. `Real` is checked at runtime
 //! to ensure that it is never `NaN`, while `Finite` adds the additional constraint
-//! that it can never be infinite, and `NonNeg` requires that it have a postive sign.  
+//! that it can never be infinite. `NonNeg` requires that it have a postive sign, and
+//! `Positive` additionally rules out zero. [`NonPos`] and [`Negative`] are their mirror images,
+//! and negating any of these four types produces its mirror.
+//! There are also some more specialized types: [`NonZero`] rules out zero of either sign
+//! and is useful as a divisor, while [`UnitInterval`] and [`SignedUnit`] are restricted
+//! to `[0, 1]` and `[-1, 1]` respectively.
+//! [`Normal`] is a `Finite` that can never be subnormal, and [`Bounded`] is restricted
+//! to a range provided by a marker type implementing [`Bounds`].
+//! [`Radians`] and [`Degrees`] wrap a `Finite` angle, and can be normalized to a single turn.
+//! [`Probability`] and [`LogProbability`] represent probabilities in linear and log space.
+//! [`Vec2`] and [`Vec3`] are vectors of checked floats, which can be normalized into a [`UnitVec`].
+//! [`Quantity`] attaches a [unit of measure](mod@unit) to any of these types.
+//! [`Complex`] is a complex number made up of two checked floats.
+//! [`Interval`] bounds the rounding error of a computation, by rounding its endpoints outwards.
+//! [`DoubleReal`] is a pair of `Finite` numbers, which together have twice the precision of one.
+//! [`Dual`] carries a derivative along with its value, for automatic differentiation.
+//! [`OptionReal`], [`OptionFinite`] and [`OptionNonNeg`] store `None` as NaN, so they are no bigger than a float.
+//! [`AtomicReal`], [`AtomicFinite`] and [`AtomicNonNeg`] can be shared and updated between threads.
+//! [`TotalOrd`] and [`CollapsedOrd`] sort and hash raw floats, including NaN, so they can be cleaned up before being checked.
+//!
+//! Each of these types is an alias for [`Checked`], parameterized by a
+//! [constraint](constraint::Constraint). Constraints can be combined with
+//! [`And`](constraint::And), as in [`FiniteNonNeg`], and you can define your own.
 //!
 //! For the sake of brevity, we will only discuss `Real`, but understand that
-//! everything applies to `Finite` and `NonNeg` as well.  
+//! everything applies to `Finite`, `NonNeg` and `Positive` as well.  
 //!
 //! # Checking behavior
 //! A `NaN`-check is inserted in every single operation and method. If a `NaN`
@@ -13,7 +35,7 @@
 //!
 //! Note that these checks will normally only be present in debug builds.
 //! This is consistent with Rust's philosphy for integer overflowing: panic
-//! in debug mode, and allow fast-but-likely-incorrect bevhavior in release mode.  
+//! in debug mode, and allow fast-but-likely-incorrect bevhavior in release mode.\
 //! If you want these checks to be present no matter what, enable the `strict` feature.
 //!
 //! # Fallible API
@@ -21,6 +43,42 @@
 //! otherwise panic. These are the `try_*` methods defined on `Real`, `Finite`, etc.,
 //! and they will perform `NaN` checks whether or not debug mode is enabled.
 //!
+//! # Conversions
+//! The types below convert into the wider types listed with `From`, and back with `TryFrom`,
+//! which fails with the error type of the narrower type:
+//! * [`Finite`], [`NonNeg`], [`NonPos`] and [`NonZero`] convert into `Real`.
+//! * `Positive` converts into `NonNeg`, `NonZero` and `Real`, and `Negative` converts into `NonPos`, `NonZero` and `Real`.
+//! * [`FiniteNonNeg`] converts into `Finite`, `NonNeg` and `Real`.
+//! * `Normal` and `SignedUnit` convert into `Finite` and `Real`.
+//! * `UnitInterval` converts into `SignedUnit`, `FiniteNonNeg`, `NonNeg`, `Finite` and `Real`.
+//! * `LogProbability` converts into `NonPos` and `Real`.
+//! * `Bounded` converts into `Finite` and `Real`.
+//!
+//! Every type can also be created from an `f32` or `f64` with `TryFrom`, and converted back with `From`.
+//!
+//! The built-in types also convert from `f32` to `f64` with `From`, which is always exact.
+//! Converting back uses `TryFrom`, which fails with a [`NarrowError`] if the value overflows or
+//! no longer upholds its constraint, and `try_narrow_exact` also fails if the value had to be rounded.
+//!
+//! Integers that always fit convert into `Finite` with `From`, while larger integers use `TryFrom`,
+//! which fails with an [`InexactError`] if the integer would be rounded. `Finite` and `NonNeg` convert
+//! back with methods such as `try_to_i32`, which take a [`Rounding`] mode and fail with an
+//! [`OutOfRangeError`] instead of saturating like `as`.
+//!
+//! # Mixed arithmetic
+//! The arithmetic operators accept any right-hand side that converts into a raw float, so they
+//! always return the type of the left-hand side, and coherence rules forbid adding operator impls
+//! for specific pairs of types. When both operands together guarantee a more precise result,
+//! a named method is provided instead:
+//! * Dividing by a [`NonZero`] never produces NaN for a finite dividend, so `Finite::div_non_zero`
+//!   and `Finite::rem_non_zero` can never fail. `Real` has `div_non_zero` and `rem_non_zero`
+//!   along with their `try_*` counterparts, which only fail for infinite inputs.
+//! * `Finite::add_real`, `Finite::sub_real` and `Finite::mul_real` return a `Real`, as do
+//!   `Real::add_finite` and `Real::sub_finite`, since the result may be infinite but never NaN.
+//! * `NonNeg::add_non_neg` never fails, and `NonNeg::mul_non_neg` only checks for NaN.
+//! * `Positive::add_positive` and `NonNeg::add_positive` return a `Positive`, while
+//!   `Positive::mul_underflowing` returns a `NonNeg`, since the product may underflow to zero.
+//!
 //! # Comparison with similar crates
 //! TODO
 
@@ -41,12 +99,12 @@ impl<F> IntoInner<F> for F {
 }
 
 /// Module containinig traits that define required operations for floating point numbers.
-/// If the optional `num-traits` feature is enabled, these will automatically be implemented for
+/// If the optional `num` feature is enabled, these will automatically be implemented for
 /// any type implementing `num_traits::Float`.
 pub mod ops;
 
 mod bits;
-pub use bits::ToOrd;
+pub use bits::{CollapsedOrd, ToOrd, TotalOrd, TotalOrdKey};
 
 /// whether or not to panic on an invalid value.
 #[doc(hidden)]
@@ -77,6 +135,11 @@ macro_rules! assert_err {
     };
 }
 
+pub mod constraint;
+
+mod checked;
+pub use checked::Checked;
+
 mod real;
 pub use real::{IsNan, NanError, Real};
 
@@ -84,9 +147,71 @@ mod finite;
 pub use finite::{Finite, InfiniteError, IsFinite};
 
 mod non_neg;
-pub use non_neg::{IsNegative, NegativeError, NonNeg};
+pub use non_neg::{FiniteNonNeg, IsNegative, NegativeError, NonNeg};
+
+mod positive;
+pub use positive::{IsPositive, IsZero, Positive, PositiveError};
+
+mod non_pos;
+pub use non_pos::{NonPos, NonPosError};
+
+mod negative;
+pub use negative::{Negative, NotNegativeError};
+
+mod non_zero;
+pub use non_zero::{IsNonZero, NonZero, ZeroError};
+
+mod unit_interval;
+pub use unit_interval::{IsUnitInterval, UnitInterval, UnitIntervalError};
+
+mod signed_unit;
+pub use signed_unit::{IsSignedUnit, SignedUnit, SignedUnitError};
+
+mod normal;
+pub use normal::{IsSubnormal, Normal, NormalError};
+
+mod bounded;
+pub use bounded::{Bounded, Bounds, BoundsError};
+
+mod angle;
+pub use angle::{Degrees, Radians};
+
+mod probability;
+pub use probability::{IsLogProbability, LogProbability, LogProbabilityError, Probability};
+
+mod vector;
+pub use vector::{UnitVec, Vec2, Vec3, ZeroLengthError};
+
+pub mod unit;
+
+mod quantity;
+pub use quantity::Quantity;
+
+mod complex;
+pub use complex::{Complex, ComplexFloat};
+
+mod interval;
+pub use interval::{Interval, IntervalError, IntervalFloat};
+
+mod double;
+pub use double::{DoubleFloat, DoubleReal};
+
+mod dual;
+pub use dual::{Dual, DualFloat};
+
+mod option;
+pub use option::{OptionChecked, OptionFinite, OptionFloat, OptionNonNeg, OptionReal};
+
+mod atomic;
+pub use atomic::{AtomicChecked, AtomicFinite, AtomicFloat, AtomicNonNeg, AtomicReal};
+
+mod width;
+pub use width::NarrowError;
+
+mod int;
+pub use int::{InexactError, OutOfRangeError, Rounding};
 
-#[cfg(feature = "num-traits")]
+#[cfg(feature = "num")]
 pub mod num;
 
 #[track_caller]
@@ -1,82 +1,3 @@
-macro_rules! ctor_impls {
-    ($ty:ident <F: $bound:ident>, $msg:literal) => {
-        impl<F: $bound> $ty<F> {
-            /// Creates a new checked float.
-            /// # Panics
-            #[doc = $msg]
-            /// Note that this fn will *not* panic in release mode, unless the `strict` feature flag is set.
-            #[track_caller]
-            pub fn new(val: F) -> Self {
-                if $crate::STRICT {
-                    $crate::unwrap_display(Self::try_new(val))
-                } else {
-                    // SAFETY: lol
-                    unsafe { Self::unchecked(val) }
-                }
-            }
-        }
-    };
-}
-
-macro_rules! eq_impls {
-    ($ty:ident <F: $bound:ident>) => {
-        impl<F: $bound + $crate::ToOrd, Rhs: $crate::IntoInner<F> + Copy>
-            ::core::cmp::PartialEq<Rhs> for $ty<F>
-        {
-            fn eq(&self, rhs: &Rhs) -> bool {
-                // we can ignore the case where `rhs` is NaN since
-                // we know that `self` is not NaN.
-                let rhs = (*rhs).into_inner();
-                self.val().total_eq(rhs)
-            }
-        }
-        impl<F: $bound + $crate::ToOrd> ::core::cmp::Eq for $ty<F> {}
-    };
-}
-
-macro_rules! ord_impls {
-    ($ty:ident <F : $bound:ident>) => {
-        impl<F: $bound + $crate::ToOrd, Rhs: $crate::IntoInner<F> + Copy>
-            ::core::cmp::PartialOrd<Rhs> for $ty<F>
-        {
-            fn partial_cmp(&self, rhs: &Rhs) -> Option<::core::cmp::Ordering> {
-                let rhs = (*rhs).into_inner();
-                let rhs = Self::try_new(rhs).ok()?.val().to_ord();
-                let lhs = self.val().to_ord();
-                Some(lhs.cmp(&rhs))
-            }
-        }
-        impl<F: $bound + $crate::ToOrd> ::core::cmp::Ord for $ty<F> {
-            fn cmp(&self, rhs: &Self) -> ::core::cmp::Ordering {
-                let lhs = self.val().to_ord();
-                let rhs = rhs.val().to_ord();
-                lhs.cmp(&rhs)
-            }
-        }
-
-        impl<F: $bound + $crate::ToOrd> $ty<F> {
-            /// Returns the larger of two floating point values.
-            #[must_use]
-            pub fn max(self, other: impl IntoInner<F>) -> Self {
-                let other = other.into_inner();
-                match self.partial_cmp(&other) {
-                    Some(::core::cmp::Ordering::Greater) => unsafe { Self::unchecked(other) },
-                    _ => self,
-                }
-            }
-            /// Returns the smaller of two floating point values.
-            #[must_use]
-            pub fn min(self, other: impl IntoInner<F>) -> Self {
-                let other = other.into_inner();
-                match self.partial_cmp(&other) {
-                    Some(::core::cmp::Ordering::Less) => unsafe { Self::unchecked(other) },
-                    _ => self,
-                }
-            }
-        }
-    };
-}
-
 macro_rules! round_impls {
     ($ty: ident <F : $bound: ident>) => {
         impl<F: $bound + $crate::ops::Round> $ty<F> {
@@ -117,6 +38,22 @@ macro_rules! signed_impls {
             pub fn abs(self) -> Self {
                 unsafe { Self::unchecked(self.val().abs()) }
             }
+        }
+        signed_impls!(@impl $ty<F: $bound>);
+    };
+    // The absolute value is never negative, so this arm returns the non-negative type `$out`.
+    ($ty: ident <F : $bound: ident>, abs -> $out: ident <F : $out_bound: ident>) => {
+        impl<F: $bound + $out_bound + $crate::ops::Signed> $ty<F> {
+            /// Computes the absolute value of self.
+            #[must_use]
+            pub fn abs(self) -> $out<F> {
+                unsafe { $out::unchecked(self.val().abs()) }
+            }
+        }
+        signed_impls!(@impl $ty<F: $bound>);
+    };
+    (@impl $ty: ident <F : $bound: ident>) => {
+        impl<F: $bound + $crate::ops::Signed> $ty<F> {
             /// Returns a number that represents the sign of self.
             /// * `1.0` if the number is positive, `+0.0` or `INFINITY`
             /// * `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
@@ -138,9 +75,33 @@ macro_rules! signed_impls {
     };
 }
 
+/// Implements `From<$ty>` for each wider type, and `TryFrom` in the other direction.
+macro_rules! convert_impls {
+    (@one [$($gen: tt)*] $ty: ty, $err: ty => $wide: ident <F : $wide_bound: ident $(+ $more: ident)*>) => {
+        impl<$($gen)* + $wide_bound $(+ $more)*> From<$ty> for $wide<F> {
+            #[inline]
+            fn from(val: $ty) -> Self {
+                unsafe { Self::unchecked(val.val()) }
+            }
+        }
+        impl<$($gen)* + $wide_bound $(+ $more)*> TryFrom<$wide<F>> for $ty {
+            type Error = $err;
+            #[inline]
+            fn try_from(val: $wide<F>) -> Result<Self, $err> {
+                Self::try_new(val.val())
+            }
+        }
+    };
+    (@impl $gen: tt $ty: ty, $err: ty => $($wide: ident <F : $wide_bound: ident $(+ $more: ident)*>),+) => {
+        $(convert_impls!(@one $gen $ty, $err => $wide<F: $wide_bound $(+ $more)*>);)+
+    };
+    ($ty: ident <F : $bound: ident>, $err: ty => $($wide: ident <F : $wide_bound: ident $(+ $more: ident)*>),+) => {
+        convert_impls!(@impl [F: $bound] $ty<F>, $err => $($wide<F: $wide_bound $(+ $more)*>),+);
+    };
+}
 macro_rules! sum_impls {
-    ($ty: ident <F : $bound: ident>, $err: ty, $msg: literal) => {
-        impl<F: $bound> $ty<F> {
+    (@impl [$($gen: tt)*] $ty: ty, $err: ty, $msg: literal) => {
+        impl<$($gen)*> $ty {
             /// Attempts to add two numbers.
             /// # Errors
             #[doc = $msg]
@@ -163,8 +124,9 @@ macro_rules! sum_impls {
             }
         }
 
-        impl<F: $bound + ::core::ops::Add<Output = F>, Rhs: $crate::IntoInner<F>>
-            ::core::ops::Add<Rhs> for $ty<F>
+        impl<$($gen)*, Rhs: $crate::IntoInner<F>> ::core::ops::Add<Rhs> for $ty
+        where
+            F: ::core::ops::Add<Output = F>,
         {
             type Output = Self;
             #[track_caller]
@@ -173,8 +135,9 @@ macro_rules! sum_impls {
                 Self::new(val)
             }
         }
-        impl<F: $bound + ::core::ops::Sub<Output = F>, Rhs: $crate::IntoInner<F>>
-            ::core::ops::Sub<Rhs> for $ty<F>
+        impl<$($gen)*, Rhs: $crate::IntoInner<F>> ::core::ops::Sub<Rhs> for $ty
+        where
+            F: ::core::ops::Sub<Output = F>,
         {
             type Output = Self;
             #[track_caller]
@@ -184,16 +147,18 @@ macro_rules! sum_impls {
             }
         }
 
-        impl<F: $bound + ::core::ops::Add<Output = F>, Rhs: $crate::IntoInner<F>>
-            ::core::ops::AddAssign<Rhs> for $ty<F>
+        impl<$($gen)*, Rhs: $crate::IntoInner<F>> ::core::ops::AddAssign<Rhs> for $ty
+        where
+            F: ::core::ops::Add<Output = F>,
         {
             #[track_caller]
             fn add_assign(&mut self, rhs: Rhs) {
                 *self = *self + rhs.into_inner();
             }
         }
-        impl<F: $bound + ::core::ops::Sub<Output = F>, Rhs: $crate::IntoInner<F>>
-            ::core::ops::SubAssign<Rhs> for $ty<F>
+        impl<$($gen)*, Rhs: $crate::IntoInner<F>> ::core::ops::SubAssign<Rhs> for $ty
+        where
+            F: ::core::ops::Sub<Output = F>,
         {
             #[track_caller]
             fn sub_assign(&mut self, rhs: Rhs) {
@@ -201,10 +166,13 @@ macro_rules! sum_impls {
             }
         }
     };
+    ($ty: ident <F : $bound: ident>, $err: ty, $msg: literal) => {
+        sum_impls!(@impl [F: $bound] $ty<F>, $err, $msg);
+    };
 }
 macro_rules! neg_impls {
-    ($ty: ident <F : $bound: ident>, $err: ty, $msg: literal) => {
-        impl<F: $bound> $ty<F> {
+    (@impl [$($gen: tt)*] $ty: ty, $err: ty, $msg: literal) => {
+        impl<$($gen)*> $ty {
             /// Attempts to negate a number.
             /// # Errors
             #[doc = $msg]
@@ -216,7 +184,10 @@ macro_rules! neg_impls {
                 Self::try_new(val)
             }
         }
-        impl<F: $bound + ::core::ops::Neg<Output = F>> ::core::ops::Neg for $ty<F> {
+        impl<$($gen)*> ::core::ops::Neg for $ty
+        where
+            F: ::core::ops::Neg<Output = F>,
+        {
             type Output = Self;
             #[track_caller]
             fn neg(self) -> Self {
@@ -225,11 +196,23 @@ macro_rules! neg_impls {
             }
         }
     };
+    ($ty: ident <F : $bound: ident>, $err: ty, $msg: literal) => {
+        neg_impls!(@impl [F: $bound] $ty<F>, $err, $msg);
+    };
+    ($ty: ident <F : $bound: ident>) => {
+        impl<F: $bound + ::core::ops::Neg<Output = F>> ::core::ops::Neg for $ty<F> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                // this macro arm assumes that negation always succeeds.
+                unsafe { Self::unchecked(-self.val()) }
+            }
+        }
+    };
 }
 
 macro_rules! product_impls {
-    ($ty: ident <F : $bound: ident>, $err: ty, $msg: literal) => {
-        impl<F: $bound> $ty<F> {
+    (@impl [$($gen: tt)*] $ty: ty, $err: ty, $msg: literal) => {
+        impl<$($gen)*> $ty {
             /// Attempts to multiply two numbers.
             /// # Errors
             #[doc = $msg]
@@ -262,8 +245,9 @@ macro_rules! product_impls {
             }
         }
 
-        impl<F: $bound + ::core::ops::Mul<Output = F>, Rhs: $crate::IntoInner<F>>
-            ::core::ops::Mul<Rhs> for $ty<F>
+        impl<$($gen)*, Rhs: $crate::IntoInner<F>> ::core::ops::Mul<Rhs> for $ty
+        where
+            F: ::core::ops::Mul<Output = F>,
         {
             type Output = Self;
             #[track_caller]
@@ -272,8 +256,9 @@ macro_rules! product_impls {
                 Self::new(val)
             }
         }
-        impl<F: $bound + ::core::ops::Div<Output = F>, Rhs: $crate::IntoInner<F>>
-            ::core::ops::Div<Rhs> for $ty<F>
+        impl<$($gen)*, Rhs: $crate::IntoInner<F>> ::core::ops::Div<Rhs> for $ty
+        where
+            F: ::core::ops::Div<Output = F>,
         {
             type Output = Self;
             #[track_caller]
@@ -282,8 +267,9 @@ macro_rules! product_impls {
                 Self::new(val)
             }
         }
-        impl<F: $bound + ::core::ops::Rem<Output = F>, Rhs: $crate::IntoInner<F>>
-            ::core::ops::Rem<Rhs> for $ty<F>
+        impl<$($gen)*, Rhs: $crate::IntoInner<F>> ::core::ops::Rem<Rhs> for $ty
+        where
+            F: ::core::ops::Rem<Output = F>,
         {
             type Output = Self;
             #[track_caller]
@@ -293,24 +279,27 @@ macro_rules! product_impls {
             }
         }
 
-        impl<F: $bound + ::core::ops::Mul<Output = F>, Rhs: $crate::IntoInner<F>>
-            ::core::ops::MulAssign<Rhs> for $ty<F>
+        impl<$($gen)*, Rhs: $crate::IntoInner<F>> ::core::ops::MulAssign<Rhs> for $ty
+        where
+            F: ::core::ops::Mul<Output = F>,
         {
             #[track_caller]
             fn mul_assign(&mut self, rhs: Rhs) {
                 *self = *self * rhs.into_inner()
             }
         }
-        impl<F: $bound + ::core::ops::Div<Output = F>, Rhs: $crate::IntoInner<F>>
-            ::core::ops::DivAssign<Rhs> for $ty<F>
+        impl<$($gen)*, Rhs: $crate::IntoInner<F>> ::core::ops::DivAssign<Rhs> for $ty
+        where
+            F: ::core::ops::Div<Output = F>,
         {
             #[track_caller]
             fn div_assign(&mut self, rhs: Rhs) {
                 *self = *self / rhs.into_inner()
             }
         }
-        impl<F: $bound + ::core::ops::Rem<Output = F>, Rhs: $crate::IntoInner<F>>
-            ::core::ops::RemAssign<Rhs> for $ty<F>
+        impl<$($gen)*, Rhs: $crate::IntoInner<F>> ::core::ops::RemAssign<Rhs> for $ty
+        where
+            F: ::core::ops::Rem<Output = F>,
         {
             #[track_caller]
             fn rem_assign(&mut self, rhs: Rhs) {
@@ -318,6 +307,51 @@ macro_rules! product_impls {
             }
         }
     };
+    ($ty: ident <F : $bound: ident>, $err: ty, $msg: literal) => {
+        product_impls!(@impl [F: $bound] $ty<F>, $err, $msg);
+    };
+}
+
+// Like `product_impls!`, but for types that are closed under multiplication:
+// `Self * Self` is infallible, while multiplying or dividing by anything else is fallible.
+macro_rules! closed_product_impls {
+    ($ty: ident <F : $bound: ident>, $err: ty, $msg: literal) => {
+        impl<F: $bound> $ty<F> {
+            /// Attempts to multiply two numbers.
+            /// # Errors
+            #[doc = $msg]
+            pub fn try_mul(self, rhs: impl $crate::IntoInner<F>) -> Result<Self, $err>
+            where
+                F: ::core::ops::Mul<Output = F>,
+            {
+                let val = self.val() * rhs.into_inner();
+                Self::try_new(val)
+            }
+            /// Attempts to divide two numbers.
+            /// # Errors
+            #[doc = $msg]
+            pub fn try_div(self, rhs: impl $crate::IntoInner<F>) -> Result<Self, $err>
+            where
+                F: ::core::ops::Div<Output = F>,
+            {
+                let val = self.val() / rhs.into_inner();
+                Self::try_new(val)
+            }
+        }
+
+        impl<F: $bound + ::core::ops::Mul<Output = F>> ::core::ops::Mul for $ty<F> {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                // this macro assumes that the type is closed under multiplication.
+                unsafe { Self::unchecked(self.val() * rhs.val()) }
+            }
+        }
+        impl<F: $bound + ::core::ops::Mul<Output = F>> ::core::ops::MulAssign for $ty<F> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+    };
 }
 
 macro_rules! pow_methods {
@@ -356,6 +390,33 @@ macro_rules! pow_methods {
             Self::new(val)
         }
     };
+    // Squares are never negative, so this arm adds `square_powi`, which returns `$out`.
+    ($f: ident, $err: ty, $msg: literal, -> $out: ident <F : $out_bound: ident>) => {
+        pow_methods!($f, $err, $msg);
+
+        /// Attempts to raise `self` to the power `n` and square the result, computing `(x^n)^2`.
+        /// # Errors
+        #[doc = $msg]
+        pub fn try_square_powi(self, n: i32) -> Result<$out<F>, $err>
+        where
+            F: $out_bound + ::core::ops::Mul<Output = F>,
+        {
+            let val = Self::try_new($crate::ops::square_powi(self.val(), n))?.val();
+            Ok(unsafe { $out::unchecked(val) })
+        }
+        /// Raises `self` to the power `n` and squares the result, computing `(x^n)^2`.
+        /// # Panics
+        #[doc = $msg]
+        #[track_caller]
+        #[must_use]
+        pub fn square_powi(self, n: i32) -> $out<F>
+        where
+            F: $out_bound + ::core::ops::Mul<Output = F>,
+        {
+            let val = Self::new($crate::ops::square_powi(self.val(), n)).val();
+            unsafe { $out::unchecked(val) }
+        }
+    };
 }
 macro_rules! recip_methods {
     ($f: ident, $err: ty, $msg: literal) => {
@@ -404,6 +465,32 @@ macro_rules! sqrt_methods {
             Self::new(val)
         }
     };
+    // The square root is never negative, so this arm returns `$out`.
+    ($f:ident, $err:ty, $msg:literal, -> $out: ident <F : $out_bound: ident>) => {
+        /// Attempts to find the square root of a number.
+        /// # Errors
+        #[doc = $msg]
+        pub fn try_sqrt(self) -> Result<$out<F>, $err>
+        where
+            F: $out_bound + $crate::ops::Signed,
+        {
+            let val = Self::try_new(self.val().sqrt())?.val();
+            // `abs` turns the result of `sqrt(-0.0)` into `+0.0`.
+            Ok(unsafe { $out::unchecked(val.abs()) })
+        }
+        /// Computes the square root of a number.
+        /// # Panics
+        #[doc = $msg]
+        #[track_caller]
+        #[must_use]
+        pub fn sqrt(self) -> $out<F>
+        where
+            F: $out_bound + $crate::ops::Signed,
+        {
+            let val = Self::new(self.val().sqrt()).val();
+            unsafe { $out::unchecked(val.abs()) }
+        }
+    };
     ($f:ident) => {
         /// Computes the square root of a number.
         #[must_use]
@@ -414,6 +501,24 @@ macro_rules! sqrt_methods {
     };
 }
 macro_rules! cbrt_methods {
+    ($f:ident, $err:ty, $msg:literal) => {
+        /// Attempts to find the cube root of a number.
+        /// # Errors
+        #[doc = $msg]
+        pub fn try_cbrt(self) -> Result<Self, $err> {
+            let val = self.val().cbrt();
+            Self::try_new(val)
+        }
+        /// Computes the cube root of a number.
+        /// # Panics
+        #[doc = $msg]
+        #[track_caller]
+        #[must_use]
+        pub fn cbrt(self) -> Self {
+            let val = self.val().cbrt();
+            Self::new(val)
+        }
+    };
     ($f:ident) => {
         /// Computes the cube root of a number.
         #[must_use]
@@ -445,6 +550,33 @@ macro_rules! hypot_methods {
             Self::new(val)
         }
     };
+    // The hypotenuse is never negative, so this arm returns `$out`.
+    ($f:ident, $err: ty, $msg: literal, -> $out: ident <F : $out_bound: ident>) => {
+        /// Attempts to calculate the length of the hypotenuse of a right-angle triangle given legs of length `x` and `y`.
+        ///
+        /// Equivalent to `sqrt(x^2 + y^2)`.
+        /// # Errors
+        #[doc = $msg]
+        pub fn try_hypot(self, other: impl $crate::IntoInner<F>) -> Result<$out<F>, $err>
+        where
+            F: $out_bound,
+        {
+            let val = Self::try_new(self.val().hypot(other.into_inner()))?.val();
+            Ok(unsafe { $out::unchecked(val) })
+        }
+        /// Calculates the length of the hypotenuse of a right-angle triangle given legs of length `x` and `y`.
+        /// # Panics
+        #[doc = $msg]
+        #[track_caller]
+        #[must_use]
+        pub fn hypot(self, other: impl $crate::IntoInner<F>) -> $out<F>
+        where
+            F: $out_bound,
+        {
+            let val = Self::new(self.val().hypot(other.into_inner())).val();
+            unsafe { $out::unchecked(val) }
+        }
+    };
 }
 
 macro_rules! exp_methodss {
@@ -463,13 +595,6 @@ macro_rules! exp_methodss {
             let val = self.val().exp2();
             Self::try_new(val)
         }
-        /// Attempts to find `e^(self) - 1` in a way that is accurate even if the number is close to zero.
-        /// # Errors
-        #[doc = $msg]
-        pub fn try_exp_m1(self) -> Result<Self, $err> {
-            let val = self.val().exp_m1();
-            Self::try_new(val)
-        }
 
         /// Computes `e^(self)`, the exponential function.
         /// # Panics
@@ -489,6 +614,65 @@ macro_rules! exp_methodss {
             let val = self.val().exp2();
             Self::new(val)
         }
+        exp_methodss!(@exp_m1 $f, $err, $msg);
+    };
+    // Exponentials are never negative, so this arm returns `$out` from `exp` and `exp2`.
+    ($f:ident, $err:ty, $msg:literal, -> $out: ident <F : $out_bound: ident>) => {
+        /// Attempts to find `e^(self)`, the exponential function.
+        /// # Errors
+        #[doc = $msg]
+        pub fn try_exp(self) -> Result<$out<F>, $err>
+        where
+            F: $out_bound,
+        {
+            let val = Self::try_new(self.val().exp())?.val();
+            Ok(unsafe { $out::unchecked(val) })
+        }
+        /// Attempts to find `2^(self)`.
+        /// # Errors
+        #[doc = $msg]
+        pub fn try_exp2(self) -> Result<$out<F>, $err>
+        where
+            F: $out_bound,
+        {
+            let val = Self::try_new(self.val().exp2())?.val();
+            Ok(unsafe { $out::unchecked(val) })
+        }
+
+        /// Computes `e^(self)`, the exponential function.
+        /// # Panics
+        #[doc = $msg]
+        #[track_caller]
+        #[must_use]
+        pub fn exp(self) -> $out<F>
+        where
+            F: $out_bound,
+        {
+            let val = Self::new(self.val().exp()).val();
+            unsafe { $out::unchecked(val) }
+        }
+        /// Computes `2^(self)`.
+        /// # Panics
+        #[doc = $msg]
+        #[track_caller]
+        #[must_use]
+        pub fn exp2(self) -> $out<F>
+        where
+            F: $out_bound,
+        {
+            let val = Self::new(self.val().exp2()).val();
+            unsafe { $out::unchecked(val) }
+        }
+        exp_methodss!(@exp_m1 $f, $err, $msg);
+    };
+    (@exp_m1 $f:ident, $err:ty, $msg:literal) => {
+        /// Attempts to find `e^(self) - 1` in a way that is accurate even if the number is close to zero.
+        /// # Errors
+        #[doc = $msg]
+        pub fn try_exp_m1(self) -> Result<Self, $err> {
+            let val = self.val().exp_m1();
+            Self::try_new(val)
+        }
         /// Computes `e^(self) - 1` more accurately than performing the operations separately.
         /// # Panics
         #[doc = $msg]
@@ -505,7 +689,7 @@ macro_rules! log_methods {
         /// Attempts to find the log base `b` of `self`.
         /// # Errors
         #[doc = $msg]
-        pub fn try_log(self, b: impl IntoInner<F>) -> Result<Self, $err> {
+        pub fn try_log(self, b: impl $crate::IntoInner<F>) -> Result<Self, $err> {
             let val = self.val().log(b.into_inner());
             Self::try_new(val)
         }
@@ -543,7 +727,7 @@ macro_rules! log_methods {
         #[doc = $msg]
         #[track_caller]
         #[must_use]
-        pub fn log(self, b: impl IntoInner<F>) -> Self {
+        pub fn log(self, b: impl $crate::IntoInner<F>) -> Self {
             let val = self.val().log(b.into_inner());
             Self::new(val)
         }
@@ -586,9 +770,9 @@ macro_rules! log_methods {
     };
 }
 macro_rules! exp_impls {
-    ($ty:ident <F : $bound:ident>, $err:ty, $msg:literal) => {
+    ($ty:ident <F : $bound:ident>, $err:ty, $msg:literal $(, -> $out: ident <F : $out_bound: ident>)?) => {
         impl<F: $bound + $crate::ops::Exp> $ty<F> {
-            exp_methodss!(F, $err, $msg);
+            exp_methodss!(F, $err, $msg $(, -> $out<F: $out_bound>)?);
             log_methods!(F, $err, $msg);
         }
     };
@@ -756,7 +940,7 @@ macro_rules! atan2_methods {
         /// Attempts to compute the four quadrant arctangent of self (`y`) and other (`x`) in radians.
         /// # Errors
         #[doc = $msg]
-        pub fn try_atan2(self, other: impl IntoInner<F>) -> Result<Self, $err> {
+        pub fn try_atan2(self, other: impl $crate::IntoInner<F>) -> Result<Self, $err> {
             let val = self.val().atan2(other.into_inner());
             Self::try_new(val)
         }
@@ -765,7 +949,7 @@ macro_rules! atan2_methods {
         #[doc = $msg]
         #[track_caller]
         #[must_use]
-        pub fn atan2(self, other: impl IntoInner<F>) -> Self {
+        pub fn atan2(self, other: impl $crate::IntoInner<F>) -> Self {
             let val = self.val().atan2(other.into_inner());
             Self::new(val)
         }
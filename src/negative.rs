@@ -0,0 +1,136 @@
+use crate::{
+    constraint::NegativeC, Checked, IsNan, IsNonZero, IsPositive, NonPos, NonZero, Positive, Real,
+};
+
+/// The error produced when zero, a positive, or NaN value is encountered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotNegativeError;
+impl std::fmt::Display for NotNegativeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "encountered zero, a positive or NaN unexpectedly")
+    }
+}
+
+/// A floating point number that is strictly less than zero.
+///
+/// Negative infinity is allowed, just like with [`NonPos`](crate::NonPos).
+pub type Negative<F> = Checked<F, NegativeC>;
+
+impl<F: IsPositive> Negative<F> {
+    /// Computes the absolute value of self.
+    #[must_use]
+    pub fn abs(self) -> Positive<F> {
+        unsafe { Positive::unchecked(self.val().abs()) }
+    }
+    /// Returns `-1.0`, since every value has a negative sign.
+    #[must_use]
+    pub fn signum(self) -> Self {
+        unsafe { Self::unchecked(self.val().signum()) }
+    }
+}
+
+// rounding is not defined, since it could produce zero
+sum_impls!(
+    Negative<F: IsPositive>,
+    NotNegativeError,
+    "If the result is zero, positive or NaN."
+);
+product_impls!(
+    Negative<F: IsPositive>,
+    NotNegativeError,
+    "If the result is zero, positive or NaN."
+);
+impl<F: IsPositive + crate::ops::Pow> Negative<F> {
+    pow_methods!(
+        F,
+        NotNegativeError,
+        "If the result is zero, positive or NaN."
+    );
+    recip_methods!(
+        F,
+        NotNegativeError,
+        "If the result is zero (caused if the input is negative infinity)."
+    );
+    cbrt_methods!(F);
+}
+
+impl<F: IsPositive + ::core::ops::Neg<Output = F>> ::core::ops::Neg for Negative<F> {
+    type Output = Positive<F>;
+    fn neg(self) -> Positive<F> {
+        // negation always flips the sign
+        unsafe { Positive::unchecked(-self.val()) }
+    }
+}
+impl<F: IsPositive + ::core::ops::Neg<Output = F>> ::core::ops::Neg for Positive<F> {
+    type Output = Negative<F>;
+    fn neg(self) -> Negative<F> {
+        unsafe { Negative::unchecked(-self.val()) }
+    }
+}
+
+convert_impls!(
+    Negative<F: IsPositive>,
+    NotNegativeError => NonPos<F: IsPositive>,
+    NonZero<F: IsNonZero>,
+    Real<F: IsNan>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! neg {
+        ($f: expr) => {
+            Negative::new($f)
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered zero, a positive or NaN unexpectedly")]
+    fn assert_new_nan() {
+        neg!(-f32::NAN);
+    }
+    #[test]
+    #[should_panic(expected = "encountered zero, a positive or NaN unexpectedly")]
+    fn assert_new_zero() {
+        neg!(-0.0f32);
+    }
+    #[test]
+    #[should_panic(expected = "encountered zero, a positive or NaN unexpectedly")]
+    fn assert_new_pos() {
+        neg!(1.0f32);
+    }
+
+    #[test]
+    fn unchecked() {
+        let neg = unsafe { Negative::unchecked(1.0f32) };
+        assert!(neg.val().is_sign_positive());
+    }
+
+    #[test]
+    fn assert_nan() {
+        assert_err!(neg!(-1.0f32).try_add(1.0));
+        assert_err!(neg!(-1.0f32).try_sub(-1.0));
+        assert_err!(neg!(-1.0f32).try_mul(-1.0));
+        assert_err!(neg!(-1.0f32).try_div(f32::INFINITY));
+        assert_err!(neg!(f32::NEG_INFINITY).try_recip());
+        assert_err!(neg!(-1.0f32).try_powi(2));
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(neg!(-2.0f32) + 1.0, neg!(-1.0));
+        assert_eq!(neg!(-2.0f32) - 1.0, neg!(-3.0));
+        assert_eq!(neg!(-5.0f32) * 2.0, neg!(-10.0));
+        assert_eq!(neg!(-8.0f32) / 2.0, neg!(-4.0));
+        assert_eq!(neg!(-2.0f32).recip(), neg!(-0.5));
+        assert_eq!(neg!(-27.0f32).cbrt(), neg!(-3.0));
+    }
+
+    #[test]
+    fn assert_neg() {
+        assert_eq!(-neg!(-1.0f32), Positive::new(1.0));
+        assert_eq!(-Positive::new(1.0f32), neg!(-1.0));
+        assert_eq!(neg!(-2.0f32).abs(), Positive::new(2.0));
+    }
+}
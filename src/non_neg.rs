@@ -1,7 +1,11 @@
-use crate::{ops::Signed, IntoInner};
+use crate::{
+    constraint::{self, And, AndError, NonNegC},
+    ops::Signed,
+    Checked, Finite, InfiniteError, IsFinite, IsNan, NanError, Real,
+};
 
 /// The error produced when a negative or NaN value is encountered.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NegativeError;
 impl std::fmt::Display for NegativeError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -13,51 +17,20 @@ impl std::fmt::Display for NegativeError {
 pub trait IsNegative: crate::ops::Signed + crate::IsNan {}
 impl<T: Signed + crate::IsNan> IsNegative for T {}
 
-#[derive(Debug, Clone, Copy, Default)]
-#[repr(transparent)]
-pub struct NonNeg<F: IsNegative>(F);
+/// A floating point number with a positive sign, including `+0.0` and positive infinity.
+pub type NonNeg<F> = Checked<F, NonNegC>;
 
-impl<F: IsNegative> NonNeg<F> {
-    /// Attempts to create a new `NonNeg`ative float.
-    /// # Errors
-    /// If the value is negative or NaN.
-    pub fn try_new(val: F) -> Result<Self, NegativeError> {
-        if val.is_sign_negative() || val.is_nan() {
-            Err(NegativeError)
-        } else {
-            Ok(Self(val))
-        }
-    }
-    /// Const-safe constructor for `NonNeg` that never checks the value.
-    /// # Safety
-    /// Ensure that the value can never be negative or `NaN`.
-    pub const unsafe fn unchecked(val: F) -> Self {
-        union Transmute<F: IsNegative> {
-            val: F,
-            non_neg: NonNeg<F>,
-        }
-
-        // SAFETY: `Real` is `repr(transparent)`.
-        Transmute { val }.non_neg
-    }
-    /// Gets the inner value of this number.
-    #[inline]
-    pub const fn val(self) -> F {
-        self.0
+impl<F: IsNegative + Default> Default for NonNeg<F> {
+    fn default() -> Self {
+        Self::new(F::default())
     }
 }
 
-ctor_impls!(NonNeg<F: IsNegative>, "If the number is negative or NaN.");
+/// A floating point number with a positive sign that is never infinite.
+///
+/// Unlike [`NonNeg`], this uses the generic implementation of every operation.
+pub type FiniteNonNeg<F> = Checked<F, And<constraint::FiniteC, NonNegC>>;
 
-impl<F: IsNegative> IntoInner<F> for NonNeg<F> {
-    #[inline]
-    fn into_inner(self) -> F {
-        self.val()
-    }
-}
-
-eq_impls!(NonNeg<F: IsNegative>);
-ord_impls!(NonNeg<F: IsNegative>);
 round_impls!(NonNeg<F: IsNegative>);
 signed_impls!(NonNeg<F: IsNegative>);
 sum_impls!(
@@ -65,7 +38,7 @@ sum_impls!(
     NegativeError,
     "If the result is negative or NaN."
 );
-// neg is not defined
+// neg produces a `NonPos`, and is defined in `non_pos.rs`
 product_impls!(
     NonNeg<F: IsNegative>,
     NegativeError,
@@ -84,6 +57,173 @@ exp_impls!(
     "If the result is negative or NaN."
 );
 
+// The methods below return a wider type than `Self`, for results that `NonNeg` can't hold.
+
+impl<F: IsNegative> NonNeg<F> {
+    /// Attempts to subtract two numbers, where the result may be negative.
+    /// # Errors
+    /// If the result is NaN, which happens when subtracting infinity from infinity.
+    pub fn try_sub_real(self, rhs: impl crate::IntoInner<F>) -> Result<Real<F>, NanError>
+    where
+        F: ::core::ops::Sub<Output = F>,
+    {
+        Real::try_new(self.val() - rhs.into_inner())
+    }
+    /// Subtracts two numbers, where the result may be negative.
+    /// # Panics
+    /// If the result is NaN, which happens when subtracting infinity from infinity.
+    #[track_caller]
+    #[must_use]
+    pub fn sub_real(self, rhs: impl crate::IntoInner<F>) -> Real<F>
+    where
+        F: ::core::ops::Sub<Output = F>,
+    {
+        Real::new(self.val() - rhs.into_inner())
+    }
+}
+
+impl<F: IsNegative> NonNeg<F> {
+    /// Adds two non-negative numbers.
+    #[must_use]
+    pub fn add_non_neg(self, rhs: impl Into<Self>) -> Self
+    where
+        F: ::core::ops::Add<Output = F>,
+    {
+        unsafe { Self::unchecked(self.val() + rhs.into().val()) }
+    }
+    /// Attempts to multiply two non-negative numbers.
+    /// # Errors
+    /// If the result is NaN, which happens when multiplying zero by infinity.
+    pub fn try_mul_non_neg(self, rhs: impl Into<Self>) -> Result<Self, NanError>
+    where
+        F: ::core::ops::Mul<Output = F>,
+    {
+        let val = self.val() * rhs.into().val();
+        if val.is_nan() {
+            Err(NanError)
+        } else {
+            Ok(unsafe { Self::unchecked(val) })
+        }
+    }
+    /// Multiplies two non-negative numbers.
+    /// # Panics
+    /// If the result is NaN, which happens when multiplying zero by infinity.
+    #[track_caller]
+    #[must_use]
+    pub fn mul_non_neg(self, rhs: impl Into<Self>) -> Self
+    where
+        F: ::core::ops::Mul<Output = F>,
+    {
+        crate::unwrap_display(self.try_mul_non_neg(rhs))
+    }
+}
+
+impl<F: IsNegative + crate::ops::Exp> NonNeg<F> {
+    /// Attempts to find the log base `b` of `self`, which may be negative.
+    /// # Errors
+    /// If the result is NaN (caused if `b` is negative or one).
+    pub fn try_log_real(self, b: impl crate::IntoInner<F>) -> Result<Real<F>, NanError> {
+        let val = self.val().log(b.into_inner());
+        Real::try_new(val)
+    }
+    /// Finds the log base `b` of `self`, which may be negative.
+    /// # Panics
+    /// If the result is NaN (caused if `b` is negative or one).
+    #[track_caller]
+    #[must_use]
+    pub fn log_real(self, b: impl crate::IntoInner<F>) -> Real<F> {
+        let val = self.val().log(b.into_inner());
+        Real::new(val)
+    }
+    /// Computes the natural log (base e) of `self`, which is negative for inputs below one.
+    ///
+    /// Unlike [`NonNeg::ln`], this can never fail: the log of zero is negative infinity.
+    #[must_use]
+    pub fn ln_real(self) -> Real<F> {
+        unsafe { Real::unchecked(self.val().ln()) }
+    }
+    /// Computes the log base 2 of `self`, which is negative for inputs below one.
+    #[must_use]
+    pub fn log2_real(self) -> Real<F> {
+        unsafe { Real::unchecked(self.val().log2()) }
+    }
+    /// Computes the log base 10 of `self`, which is negative for inputs below one.
+    #[must_use]
+    pub fn log10_real(self) -> Real<F> {
+        unsafe { Real::unchecked(self.val().log10()) }
+    }
+}
+
+impl<F: IsNegative + IsFinite + crate::ops::Trig> NonNeg<F> {
+    /// Attempts to compute the sine of a number (in radians).
+    /// # Errors
+    /// If `self` is infinite.
+    pub fn try_sin(self) -> Result<Finite<F>, InfiniteError> {
+        Finite::try_new(self.val().sin())
+    }
+    /// Attempts to compute the cosine of a number (in radians).
+    /// # Errors
+    /// If `self` is infinite.
+    pub fn try_cos(self) -> Result<Finite<F>, InfiniteError> {
+        Finite::try_new(self.val().cos())
+    }
+    /// Attempts to compute the sine and cosine of a number (in radians) simultaneously.
+    /// # Errors
+    /// If `self` is infinite.
+    pub fn try_sin_cos(self) -> Result<(Finite<F>, Finite<F>), InfiniteError> {
+        let (s, c) = self.val().sin_cos();
+        Ok((Finite::try_new(s)?, Finite::try_new(c)?))
+    }
+    /// Attempts to compute the tangent of a number (in radians).
+    /// # Errors
+    /// If `self` is infinite.
+    pub fn try_tan(self) -> Result<Finite<F>, InfiniteError> {
+        Finite::try_new(self.val().tan())
+    }
+
+    /// Computes the sine of a number (in radians).
+    /// # Panics
+    /// If `self` is infinite.
+    #[track_caller]
+    #[must_use]
+    pub fn sin(self) -> Finite<F> {
+        Finite::new(self.val().sin())
+    }
+    /// Computes the cosine of a number (in radians).
+    /// # Panics
+    /// If `self` is infinite.
+    #[track_caller]
+    #[must_use]
+    pub fn cos(self) -> Finite<F> {
+        Finite::new(self.val().cos())
+    }
+    /// Computes the sine and cosine of a number (in radians) simultaneously.
+    /// # Panics
+    /// If `self` is infinite.
+    #[track_caller]
+    #[must_use]
+    pub fn sin_cos(self) -> (Finite<F>, Finite<F>) {
+        let (s, c) = self.val().sin_cos();
+        (Finite::new(s), Finite::new(c))
+    }
+    /// Computes the tangent of a number (in radians).
+    /// # Panics
+    /// If `self` is infinite.
+    #[track_caller]
+    #[must_use]
+    pub fn tan(self) -> Finite<F> {
+        Finite::new(self.val().tan())
+    }
+}
+
+convert_impls!(NonNeg<F: IsNegative>, NegativeError => Real<F: IsNan>);
+convert_impls!(
+    @impl [F: IsNegative + IsFinite] FiniteNonNeg<F>,
+    AndError<InfiniteError, NegativeError> => Finite<F: IsFinite>,
+    NonNeg<F: IsNegative>,
+    Real<F: IsNan>
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,22 +235,22 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "encountered a negative or NaN unexpectedly")]
     fn assert_new_nan() {
         nn!(f32::NAN);
     }
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "encountered a negative or NaN unexpectedly")]
     fn assert_new_nan2() {
         nn!(-f32::NAN);
     }
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "encountered a negative or NaN unexpectedly")]
     fn assert_new_inf2() {
         nn!(f32::NEG_INFINITY);
     }
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "encountered a negative or NaN unexpectedly")]
     fn assert_new_neg() {
         nn!(-1.0f32);
     }
@@ -146,7 +286,7 @@ mod tests {
 
     #[test]
     #[allow(clippy::bool_assert_comparison)]
-    #[allow(clippy::cmp_nan)]
+    #[allow(invalid_nan_comparisons)]
     fn assert_cmp_weird() {
         assert_eq!(nn!(0.0f32), nn!(0.0));
         assert_eq!(nn!(0.0f32), crate::Finite::new(-0.0));
@@ -177,4 +317,44 @@ mod tests {
         assert_epsilon!(nn!(1000.0f32).log10(), nn!(3.0));
         assert_epsilon!(nn!(147.413_16f32).ln_1p(), nn!(5.0));
     }
+
+    #[test]
+    fn assert_wide_output() {
+        assert_eq!(nn!(1.0f32).sub_real(3.0), Real::new(-2.0));
+        assert_err!(nn!(f32::INFINITY).try_sub_real(f32::INFINITY));
+        assert_epsilon!(nn!(0.5f32).ln_real(), Real::new(-std::f32::consts::LN_2));
+        assert_eq!(nn!(0.0f32).ln_real(), Real::new(f32::NEG_INFINITY));
+        assert_eq!(nn!(0.25f32).log2_real(), Real::new(-2.0));
+        assert_eq!(nn!(0.01f32).log10_real(), Real::new(-2.0));
+        assert_epsilon!(nn!(0.25f32).log_real(4.0), Real::new(-1.0));
+        assert_eq!(nn!(0.0f32).log_real(2.0), Real::new(f32::NEG_INFINITY));
+        assert_err!(nn!(1.0f32).try_log_real(1.0));
+        assert_err!(nn!(2.0f32).try_log_real(-2.0));
+
+        assert_epsilon!(nn!(std::f32::consts::PI).sin(), Finite::new(0.0));
+        assert_epsilon!(nn!(std::f32::consts::PI).cos(), Finite::new(-1.0));
+        assert_epsilon!(nn!(std::f32::consts::FRAC_PI_4).tan(), Finite::new(1.0));
+        assert_err!(nn!(f32::INFINITY).try_sin());
+        assert_err!(nn!(f32::INFINITY).try_sin_cos());
+    }
+
+    #[test]
+    fn assert_non_neg_output() {
+        assert_eq!(
+            nn!(f32::MAX).add_non_neg(nn!(f32::INFINITY)),
+            nn!(f32::INFINITY)
+        );
+        assert_eq!(nn!(2.0f32).mul_non_neg(nn!(3.0)), nn!(6.0));
+        assert_eq!(
+            nn!(0.0f32).try_mul_non_neg(nn!(f32::INFINITY)),
+            Err(NanError)
+        );
+
+        // mixed pairs
+        assert_eq!(nn!(1.0f32).add_non_neg(FiniteNonNeg::new(2.0)), nn!(3.0));
+        assert_eq!(
+            nn!(0.0f32).try_mul_non_neg(crate::Positive::new(f32::INFINITY)),
+            Err(NanError)
+        );
+    }
 }
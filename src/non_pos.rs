@@ -0,0 +1,139 @@
+use crate::{constraint::NonPosC, Checked, IsNan, IsPositive, NonNeg, Real};
+
+/// The error produced when a positive or NaN value is encountered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonPosError;
+impl std::fmt::Display for NonPosError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "encountered a positive or NaN unexpectedly")
+    }
+}
+
+/// A floating point number that is less than or equal to zero, including negative infinity.
+///
+/// This is the mirror image of [`NonNeg`], except that zero of either sign is allowed.
+pub type NonPos<F> = Checked<F, NonPosC>;
+
+impl<F: IsPositive> NonPos<F> {
+    /// Computes the absolute value of self.
+    #[must_use]
+    pub fn abs(self) -> NonNeg<F> {
+        unsafe { NonNeg::unchecked(self.val().abs()) }
+    }
+    /// Returns a number that represents the sign of self.
+    /// * `1.0` if the number is `+0.0`
+    /// * `-1.0` otherwise
+    #[must_use]
+    pub fn signum(self) -> Real<F> {
+        unsafe { Real::unchecked(self.val().signum()) }
+    }
+}
+
+// rounding is not defined, since `fract` can produce `+0.0`
+sum_impls!(
+    NonPos<F: IsPositive>,
+    NonPosError,
+    "If the result is positive or NaN."
+);
+product_impls!(
+    NonPos<F: IsPositive>,
+    NonPosError,
+    "If the result is positive or NaN."
+);
+impl<F: IsPositive + crate::ops::Pow> NonPos<F> {
+    pow_methods!(F, NonPosError, "If the result is positive or NaN.");
+    recip_methods!(
+        F,
+        NonPosError,
+        "If the result is positive (caused if the input is `+0.0`)."
+    );
+    sqrt_methods!(
+        F,
+        NonPosError,
+        "If the result is NaN (caused if the input is anything other than zero)."
+    );
+    cbrt_methods!(F);
+}
+
+impl<F: IsPositive + ::core::ops::Neg<Output = F>> ::core::ops::Neg for NonPos<F> {
+    type Output = NonNeg<F>;
+    fn neg(self) -> NonNeg<F> {
+        // `abs` is the same as negation, except that it maps `+0.0` to itself.
+        unsafe { NonNeg::unchecked(self.val().abs()) }
+    }
+}
+impl<F: IsPositive + ::core::ops::Neg<Output = F>> ::core::ops::Neg for NonNeg<F> {
+    type Output = NonPos<F>;
+    fn neg(self) -> NonPos<F> {
+        unsafe { NonPos::unchecked(-self.val()) }
+    }
+}
+
+convert_impls!(NonPos<F: IsPositive>, NonPosError => Real<F: IsNan>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! np {
+        ($f: expr) => {
+            NonPos::new($f)
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered a positive or NaN unexpectedly")]
+    fn assert_new_nan() {
+        np!(-f32::NAN);
+    }
+    #[test]
+    fn assert_new_zero() {
+        assert!(np!(0.0f32).val().is_sign_positive());
+        assert!(np!(-0.0f32).val().is_sign_negative());
+    }
+    #[test]
+    #[should_panic(expected = "encountered a positive or NaN unexpectedly")]
+    fn assert_new_pos() {
+        np!(1.0f32);
+    }
+
+    #[test]
+    fn unchecked() {
+        let np = unsafe { NonPos::unchecked(f32::INFINITY) };
+        assert!(np.val().is_infinite());
+    }
+
+    #[test]
+    fn assert_nan() {
+        assert_err!(np!(f32::NEG_INFINITY).try_add(f32::INFINITY));
+        assert_err!(np!(-1.0f32).try_add(2.0));
+        assert_err!(np!(-1.0f32).try_sub(-2.0));
+        assert_err!(np!(-1.0f32).try_mul(-1.0));
+        assert_err!(np!(-1.0f32).try_powi(2));
+        assert_err!(np!(-1.0f32).try_sqrt());
+        assert_err!(np!(0.0f32).try_recip());
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(np!(-2.0f32) + 1.0, np!(-1.0));
+        assert_eq!(np!(-2.0f32) - 1.0, np!(-3.0));
+        assert_eq!(np!(-5.0f32) * 2.0, np!(-10.0));
+        assert_eq!(np!(-8.0f32) / 2.0, np!(-4.0));
+        assert_eq!(np!(-2.0f32).recip(), np!(-0.5));
+        assert_eq!(np!(-0.0f32).recip(), np!(f32::NEG_INFINITY));
+        assert_eq!(np!(-27.0f32).cbrt(), np!(-3.0));
+    }
+
+    #[test]
+    fn assert_neg() {
+        assert_eq!(-np!(-1.0f32), NonNeg::new(1.0));
+        assert_eq!(-NonNeg::new(1.0f32), np!(-1.0));
+        assert!((-NonNeg::new(0.0f32)).val().is_sign_negative());
+        assert!((-np!(-0.0f32)).val().is_sign_positive());
+        assert!((-np!(0.0f32)).val().is_sign_positive());
+        assert_eq!(np!(0.0f32).signum(), Real::new(1.0));
+        assert_eq!(np!(-0.0f32).signum(), Real::new(-1.0));
+        assert_eq!(np!(-2.0f32).abs(), NonNeg::new(2.0));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    constraint::NonZeroC, positive::IsZero, Checked, Finite, IsFinite, IsNan, IsPositive, NanError,
+    Positive, Real,
+};
+
+/// The error produced when zero or NaN is encountered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroError;
+impl std::fmt::Display for ZeroError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "encountered zero or NaN unexpectedly")
+    }
+}
+
+#[doc(hidden)]
+pub trait IsNonZero: IsNan + IsZero {}
+impl<T: IsNan + IsZero> IsNonZero for T {}
+
+/// A floating point number that is neither zero (of either sign) nor NaN.
+///
+/// Infinity is allowed. This is mainly useful as a divisor: [`Finite::div_non_zero`] and
+/// [`Finite::rem_non_zero`] can never fail, while [`Real::try_div_non_zero`] and
+/// [`Real::try_rem_non_zero`] only fail for infinite inputs.
+///
+/// These are methods rather than `Div`/`Rem` impls, because coherence rules forbid those impls:
+/// the operators on [`Finite`] and [`Real`] already accept any right-hand side that converts
+/// into a raw float, including `NonZero`, and they always return the type of the left-hand side.
+pub type NonZero<F> = Checked<F, NonZeroC>;
+
+// rounding is not defined, since it could produce zero
+signed_impls!(NonZero<F: IsNonZero>, abs -> Positive<F: IsPositive>);
+sum_impls!(
+    NonZero<F: IsNonZero>,
+    ZeroError,
+    "If the result is zero or NaN."
+);
+neg_impls!(NonZero<F: IsNonZero>); // negation always succeeds for non-zero values
+product_impls!(
+    NonZero<F: IsNonZero>,
+    ZeroError,
+    "If the result is zero (caused by underflow) or NaN."
+);
+impl<F: IsNonZero + crate::ops::Pow> NonZero<F> {
+    pow_methods!(
+        F,
+        ZeroError,
+        "If the result is zero (caused by underflow) or NaN.",
+        -> Positive<F: IsPositive>
+    );
+    recip_methods!(
+        F,
+        ZeroError,
+        "If the result is zero (caused if the input is `±infinity`)."
+    );
+    sqrt_methods!(
+        F,
+        ZeroError,
+        "If the result is NaN (caused if the input is negative).",
+        -> Positive<F: IsPositive>
+    );
+    cbrt_methods!(F);
+    hypot_methods!(F, ZeroError, "If the result is NaN.", -> Positive<F: IsPositive>);
+}
+exp_impls!(
+    NonZero<F: IsNonZero>,
+    ZeroError,
+    "If the result is zero or NaN.",
+    -> Positive<F: IsPositive>
+);
+
+impl<F: IsFinite + IsNonZero> Finite<F> {
+    /// Divides `self` by a number that is known to be non-zero.
+    ///
+    /// Unlike [`Finite::try_div`], this can never fail: the quotient may overflow to infinity,
+    /// but it can never be NaN.
+    #[must_use]
+    pub fn div_non_zero(self, rhs: NonZero<F>) -> Real<F>
+    where
+        F: ::core::ops::Div<Output = F>,
+    {
+        unsafe { Real::unchecked(self.val() / rhs.val()) }
+    }
+    /// Finds the remainder of dividing `self` by a number that is known to be non-zero.
+    ///
+    /// Unlike [`Finite::try_rem`], this can never fail, since the remainder
+    /// can never be larger in magnitude than `self`.
+    #[must_use]
+    pub fn rem_non_zero(self, rhs: NonZero<F>) -> Self
+    where
+        F: ::core::ops::Rem<Output = F>,
+    {
+        unsafe { Self::unchecked(self.val() % rhs.val()) }
+    }
+}
+
+impl<F: IsNonZero> Real<F> {
+    /// Attempts to divide `self` by a number that is known to be non-zero.
+    /// # Errors
+    /// If the result is NaN, which only happens when dividing infinity by infinity.
+    pub fn try_div_non_zero(self, rhs: NonZero<F>) -> Result<Self, NanError>
+    where
+        F: ::core::ops::Div<Output = F>,
+    {
+        Self::try_new(self.val() / rhs.val())
+    }
+    /// Divides `self` by a number that is known to be non-zero.
+    /// # Panics
+    /// If the result is NaN, which only happens when dividing infinity by infinity.
+    #[track_caller]
+    #[must_use]
+    pub fn div_non_zero(self, rhs: NonZero<F>) -> Self
+    where
+        F: ::core::ops::Div<Output = F>,
+    {
+        crate::unwrap_display(self.try_div_non_zero(rhs))
+    }
+    /// Attempts to find the remainder of dividing `self` by a number that is known to be non-zero.
+    /// # Errors
+    /// If the result is NaN, which only happens when `self` is infinite.
+    pub fn try_rem_non_zero(self, rhs: NonZero<F>) -> Result<Self, NanError>
+    where
+        F: ::core::ops::Rem<Output = F>,
+    {
+        Self::try_new(self.val() % rhs.val())
+    }
+    /// Finds the remainder of dividing `self` by a number that is known to be non-zero.
+    /// # Panics
+    /// If the result is NaN, which only happens when `self` is infinite.
+    #[track_caller]
+    #[must_use]
+    pub fn rem_non_zero(self, rhs: NonZero<F>) -> Self
+    where
+        F: ::core::ops::Rem<Output = F>,
+    {
+        crate::unwrap_display(self.try_rem_non_zero(rhs))
+    }
+}
+
+convert_impls!(NonZero<F: IsNonZero>, ZeroError => Real<F: IsNan>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! nz {
+        ($f: expr) => {
+            NonZero::new($f)
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered zero or NaN unexpectedly")]
+    fn assert_new_nan() {
+        nz!(f32::NAN);
+    }
+    #[test]
+    #[should_panic(expected = "encountered zero or NaN unexpectedly")]
+    fn assert_new_zero() {
+        nz!(0.0f32);
+    }
+    #[test]
+    #[should_panic(expected = "encountered zero or NaN unexpectedly")]
+    fn assert_new_zero2() {
+        nz!(-0.0f32);
+    }
+
+    #[test]
+    fn unchecked() {
+        let nz = unsafe { NonZero::unchecked(f32::NAN) };
+        assert!(nz.val().is_nan());
+    }
+
+    #[test]
+    fn assert_nan() {
+        assert_err!(nz!(1.0f32).try_sub(1.0));
+        assert_err!(nz!(-1.0f32).try_add(1.0));
+        assert_err!(nz!(1.0f32).try_mul(0.0));
+        assert_err!(nz!(f32::MIN_POSITIVE).try_mul(f32::MIN_POSITIVE)); // underflows to zero
+        assert_err!(nz!(1.0f32).try_div(f32::NEG_INFINITY));
+        assert_err!(nz!(2.0f32).try_rem(1.0));
+
+        assert_err!(nz!(f32::NEG_INFINITY).try_recip());
+        assert_err!(nz!(-4.0f32).try_sqrt());
+
+        assert_err!(nz!(1.0f32).try_ln());
+        assert_err!(nz!(-1000.0f32).try_exp());
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(nz!(2.0f32) + 1.0, nz!(3.0));
+        assert_eq!(nz!(2.0f32) - 1.0, nz!(1.0));
+        assert_eq!(nz!(5.0f32) * 2.0, nz!(10.0));
+        assert_eq!(nz!(8.0f32) / 2.0, nz!(4.0));
+        assert_eq!(-nz!(1.0f32), nz!(-1.0));
+    }
+
+    #[test]
+    fn assert_positive_output() {
+        let abs: Positive<f32> = nz!(-2.0f32).abs();
+        assert_eq!(abs, Positive::new(2.0));
+        let sqrt: Positive<f32> = nz!(4.0f32).sqrt();
+        assert_eq!(sqrt, Positive::new(2.0));
+        let exp: Positive<f32> = nz!(-1.0f32).exp();
+        assert_epsilon!(exp.val(), 0.367_879_45);
+        let square: Positive<f32> = nz!(-0.5f32).square_powi(-1);
+        assert_eq!(square, Positive::new(4.0));
+        assert_err!(nz!(f32::MIN_POSITIVE).try_square_powi(1)); // underflows to zero
+    }
+
+    #[test]
+    fn assert_div_non_zero() {
+        let finite = Finite::new;
+        assert_eq!(finite(1.0f32).div_non_zero(nz!(2.0)), Real::new(0.5));
+        assert_eq!(
+            finite(f32::MAX).div_non_zero(nz!(0.5)),
+            Real::new(f32::INFINITY)
+        );
+        assert_eq!(
+            finite(1.0f32).div_non_zero(nz!(f32::INFINITY)),
+            Real::new(0.0)
+        );
+
+        assert_eq!(finite(7.5f32).rem_non_zero(nz!(2.0)), finite(1.5));
+        assert_eq!(finite(-1.5f32).rem_non_zero(nz!(1.0)), finite(-0.5));
+        assert_eq!(finite(1.5f32).rem_non_zero(nz!(f32::INFINITY)), finite(1.5));
+    }
+
+    #[test]
+    fn assert_real_div_non_zero() {
+        let real = Real::new;
+        assert_eq!(real(1.0f32).div_non_zero(nz!(-2.0)), real(-0.5));
+        assert_eq!(
+            real(f32::INFINITY).div_non_zero(nz!(2.0)),
+            real(f32::INFINITY)
+        );
+        assert_eq!(real(1.0f32).div_non_zero(nz!(f32::INFINITY)), real(0.0));
+        assert_err!(real(f32::INFINITY).try_div_non_zero(nz!(f32::NEG_INFINITY)));
+
+        assert_eq!(real(7.5f32).rem_non_zero(nz!(2.0)), real(1.5));
+        assert_eq!(real(1.5f32).rem_non_zero(nz!(f32::INFINITY)), real(1.5));
+        assert_err!(real(f32::INFINITY).try_rem_non_zero(nz!(2.0)));
+    }
+}
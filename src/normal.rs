@@ -0,0 +1,167 @@
+use crate::{constraint::NormalC, Checked, Finite, IsFinite, IsNan, Real};
+
+/// The error produced when a subnormal, infinite, or NaN value is encountered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalError {
+    /// The value is finite, but too small in magnitude to be represented as a normal number.
+    Subnormal,
+    /// The value is infinite or NaN.
+    NonFinite,
+}
+impl std::fmt::Display for NormalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Subnormal => write!(f, "encountered a subnormal value unexpectedly"),
+            Self::NonFinite => write!(f, "encountered infinity or NaN unexpectedly"),
+        }
+    }
+}
+
+/// Trait for a floating point number that can be checked for subnormal values.
+pub trait IsSubnormal: IsFinite {
+    fn is_subnormal(self) -> bool;
+    /// Replaces a subnormal value with zero of the same sign, leaving any other value untouched.
+    #[must_use]
+    fn flush_to_zero(self) -> Self;
+}
+
+/// A finite floating point number that is either zero or normal (never subnormal).
+pub type Normal<F> = Checked<F, NormalC>;
+
+impl<F: IsSubnormal + Default> Default for Normal<F> {
+    fn default() -> Self {
+        Self::new(F::default())
+    }
+}
+
+impl<F: IsSubnormal> Normal<F> {
+    /// Attempts to create a new `Normal` float, flushing subnormal values to zero.
+    /// # Errors
+    /// If the value is infinite or NaN.
+    pub fn try_new_flushed(val: F) -> Result<Self, NormalError> {
+        Self::try_new(val.flush_to_zero())
+    }
+    /// Creates a new `Normal` float, flushing subnormal values to zero.
+    /// # Panics
+    /// If the value is infinite or NaN.
+    /// Note that this fn will *not* panic in release mode, unless the `strict` feature flag is set.
+    #[track_caller]
+    pub fn new_flushed(val: F) -> Self {
+        Self::new(val.flush_to_zero())
+    }
+}
+
+round_impls!(Normal<F: IsSubnormal>);
+signed_impls!(Normal<F: IsSubnormal>);
+sum_impls!(
+    Normal<F: IsSubnormal>,
+    NormalError,
+    "If the result is subnormal or non-finite."
+);
+neg_impls!(Normal<F: IsSubnormal>); // negation always succeeds for normal values
+product_impls!(
+    Normal<F: IsSubnormal>,
+    NormalError,
+    "If the result is subnormal or non-finite."
+);
+impl<F: IsSubnormal + crate::ops::Pow> Normal<F> {
+    pow_methods!(F, NormalError, "If the result is subnormal or non-finite.");
+    recip_methods!(F, NormalError, "If the result is subnormal or non-finite.");
+    sqrt_methods!(F, NormalError, "If the result is non-finite.");
+    cbrt_methods!(F);
+    hypot_methods!(F, NormalError, "If the result is non-finite.");
+}
+exp_impls!(
+    Normal<F: IsSubnormal>,
+    NormalError,
+    "If the result is subnormal or non-finite."
+);
+impl<F: IsSubnormal + crate::ops::Trig> Normal<F> {
+    sin_cos_methods!(F, NormalError, "If the result is subnormal.");
+    tan_methods!(F, NormalError, "If the result is subnormal or non-finite.");
+    asin_acos_methods!(
+        F,
+        NormalError,
+        "If the result is non-finite (caused if the magnitude of the input exceeds 1)."
+    );
+    atan_methods!(F, NormalError, "If the result is subnormal.");
+    atan2_methods!(F, NormalError, "If the result is subnormal or non-finite.");
+}
+
+convert_impls!(
+    Normal<F: IsSubnormal>,
+    NormalError => Finite<F: IsFinite>,
+    Real<F: IsNan>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! normal {
+        ($f: expr) => {
+            Normal::new($f)
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
+    fn assert_new_nan() {
+        normal!(f32::NAN);
+    }
+    #[test]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
+    fn assert_new_inf() {
+        normal!(f32::INFINITY);
+    }
+    #[test]
+    #[should_panic(expected = "encountered a subnormal value unexpectedly")]
+    fn assert_new_subnormal() {
+        normal!(f32::MIN_POSITIVE / 2.0);
+    }
+
+    #[test]
+    fn unchecked() {
+        let normal = unsafe { Normal::unchecked(f32::MIN_POSITIVE / 2.0) };
+        assert!(normal.val().is_subnormal());
+    }
+
+    #[test]
+    fn assert_flushed() {
+        assert_eq!(Normal::new_flushed(f32::MIN_POSITIVE / 2.0), normal!(0.0));
+        assert!(Normal::new_flushed(-f32::MIN_POSITIVE / 2.0)
+            .val()
+            .is_sign_negative());
+        assert_eq!(Normal::new_flushed(1.5f32), normal!(1.5));
+        assert_eq!(
+            Normal::try_new_flushed(f32::NAN).unwrap_err(),
+            NormalError::NonFinite
+        );
+    }
+
+    #[test]
+    fn assert_nan() {
+        let subnormal = Err(NormalError::Subnormal);
+        let non_finite = Err(NormalError::NonFinite);
+
+        assert_eq!(normal!(f32::MAX).try_add(f32::MAX), non_finite);
+        assert_eq!(
+            normal!(f32::MIN_POSITIVE * 1.5).try_sub(f32::MIN_POSITIVE),
+            subnormal
+        );
+        assert_eq!(normal!(f32::MIN_POSITIVE).try_mul(0.5), subnormal);
+        assert_eq!(normal!(1.0f32).try_div(0.0), non_finite);
+        assert_eq!(normal!(f32::MAX).try_recip(), subnormal);
+        assert_eq!(normal!(-100.0f32).try_exp(), subnormal);
+        assert_eq!(normal!(-1.0f32).try_sqrt(), non_finite);
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(normal!(2.0f32) + 1.0, normal!(3.0));
+        assert_eq!(normal!(2.0f32) - 2.0, normal!(0.0));
+        assert_eq!(normal!(5.0f32) * 2.0, normal!(10.0));
+        assert_eq!(normal!(8.0f32) / 2.0, normal!(4.0));
+        assert_eq!(-normal!(1.0f32), normal!(-1.0));
+    }
+}
@@ -89,7 +89,7 @@ macro_rules! impl_float {
             }
 
             fn is_nan(self) -> bool {
-                if crate::check::STRICT {
+                if crate::STRICT {
                     false
                 } else {
                     <F as Float>::is_nan(self.val())
@@ -126,7 +126,7 @@ macro_rules! impl_float {
             }
 
             fn abs(self) -> Self {
-                self.abs()
+                Self::new(self.abs().val())
             }
             fn abs_sub(self, other: Self) -> Self {
                 // we're not giving this an inherent method bc its bad
@@ -155,20 +155,20 @@ macro_rules! impl_float {
                 self.powf(n)
             }
             fn sqrt(self) -> Self {
-                self.sqrt()
+                Self::new(self.sqrt().val())
             }
             fn cbrt(self) -> Self {
                 self.cbrt()
             }
             fn hypot(self, other: Self) -> Self {
-                self.hypot(other)
+                Self::new(self.hypot(other).val())
             }
 
             fn exp(self) -> Self {
-                self.exp()
+                Self::new(self.exp().val())
             }
             fn exp2(self) -> Self {
-                self.exp2()
+                Self::new(self.exp2().val())
             }
             fn exp_m1(self) -> Self {
                 self.exp_m1()
@@ -249,3 +249,23 @@ macro_rules! impl_float {
 
 impl_float!(Real, IsNan + ToOrd, NanError);
 impl_float!(Finite, IsFinite + ToOrd, InfiniteError);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hypot<F: Float>(x: F, y: F) -> F {
+        (x * x + y * y).sqrt()
+    }
+
+    #[test]
+    fn assert_float() {
+        assert_eq!(hypot(Real::new(3.0f32), Real::new(4.0)), Real::new(5.0));
+        assert_eq!(
+            hypot(Finite::new(3.0f64), Finite::new(4.0)),
+            Finite::new(5.0)
+        );
+        assert_eq!(<Finite<f32> as Zero>::zero(), Finite::new(0.0));
+        assert!(<Real<f32> as Num>::from_str_radix("nan", 10).is_err());
+    }
+}
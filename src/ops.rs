@@ -81,8 +81,74 @@ pub trait Trig: Sized + Copy {
     fn atan2(self, _: Self) -> Self;
 }
 
+/// Trait for converting angles between radians and degrees, and wrapping them to a single turn.
+pub trait Angle: Sized + Copy {
+    /// Returns a quarter turn in radians, `π/2`.
+    #[must_use]
+    fn frac_pi_2() -> Self;
+    #[must_use]
+    fn to_degrees(self) -> Self;
+    #[must_use]
+    fn to_radians(self) -> Self;
+
+    /// Wraps an angle in radians to `[0, 2π)`.
+    #[must_use]
+    fn wrap_radians(self) -> Self;
+    /// Wraps an angle in radians to `(-π, π]`.
+    #[must_use]
+    fn wrap_radians_signed(self) -> Self;
+    /// Wraps an angle in degrees to `[0, 360)`.
+    #[must_use]
+    fn wrap_degrees(self) -> Self;
+    /// Wraps an angle in degrees to `(-180, 180]`.
+    #[must_use]
+    fn wrap_degrees_signed(self) -> Self;
+    /// Computes the sine and cosine of an angle in degrees,
+    /// which should be exact for multiples of 90°.
+    #[must_use]
+    fn sin_cos_degrees(self) -> (Self, Self);
+}
+
+/// Trait for stepping to adjacent floating point numbers.
+pub trait NextFloat: Sized + Copy {
+    /// Returns the least number greater than self.
+    #[must_use]
+    fn next_up(self) -> Self;
+    /// Returns the greatest number less than self.
+    #[must_use]
+    fn next_down(self) -> Self;
+}
+
+/// Trait for fused multiply-add.
+pub trait MulAdd: Sized + Copy {
+    /// Computes `(self * a) + b` with only one rounding error.
+    #[must_use]
+    fn mul_add(self, a: Self, b: Self) -> Self;
+}
+
+/// Trait for the constants that are needed by generic algorithms.
+pub trait Consts: Sized + Copy {
+    /// Returns `1.0`.
+    #[must_use]
+    fn one() -> Self;
+    /// Returns a NaN.
+    #[must_use]
+    fn nan() -> Self;
+}
+
+/// Computes `(val^n)^2`, without overflowing the exponent.
+pub(crate) fn square_powi<F: Pow + ::core::ops::Mul<Output = F>>(val: F, n: i32) -> F {
+    if let Some(twice) = n.checked_mul(2) {
+        val.powi(twice)
+    } else {
+        let root = val.powi(n);
+        root * root
+    }
+}
+
+#[cfg_attr(feature = "num", allow(unused_macros))]
 macro_rules! impl_ops {
-    ($f: ty) => {
+    ($f: ident) => {
         impl crate::IsNan for $f {
             #[inline]
             fn is_nan(self) -> bool {
@@ -95,6 +161,74 @@ macro_rules! impl_ops {
                 <$f>::is_finite(self)
             }
         }
+        impl crate::IsZero for $f {
+            #[inline]
+            fn is_zero(self) -> bool {
+                self == 0.0
+            }
+        }
+        impl crate::IsUnitInterval for $f {
+            #[inline]
+            fn is_unit_interval(self) -> bool {
+                self.is_sign_positive() && self <= 1.0
+            }
+            #[inline]
+            fn complement(self) -> $f {
+                1.0 - self
+            }
+        }
+        impl crate::IsSignedUnit for $f {
+            #[inline]
+            fn is_signed_unit(self) -> bool {
+                self.abs() <= 1.0
+            }
+        }
+        impl crate::IsSubnormal for $f {
+            #[inline]
+            fn is_subnormal(self) -> bool {
+                <$f>::is_subnormal(self)
+            }
+            #[inline]
+            fn flush_to_zero(self) -> $f {
+                if <$f>::is_subnormal(self) {
+                    <$f>::copysign(0.0, self)
+                } else {
+                    self
+                }
+            }
+        }
+        impl crate::IsLogProbability for $f {
+            #[inline]
+            fn is_log_probability(self) -> bool {
+                self <= 0.0
+            }
+            #[inline]
+            fn log_complement(self) -> $f {
+                // Each branch avoids cancellation in the range where it's used.
+                if self > -std::$f::consts::LN_2 {
+                    Exp::ln(-Exp::exp_m1(self))
+                } else {
+                    Exp::ln_1p(-Exp::exp(self))
+                }
+            }
+            #[inline]
+            fn log_add_exp(self, other: $f) -> $f {
+                let (max, min) = if self > other { (self, other) } else { (other, self) };
+                if min == <$f>::NEG_INFINITY {
+                    // avoid computing `-inf - -inf`
+                    max
+                } else {
+                    let sum = max + Exp::ln_1p(Exp::exp(min - max));
+                    // for two log-probabilities such as `ln(p)` and `ln(1 - p)`, the sum can
+                    // round to just above zero, so clamp anything within rounding error of it.
+                    if max <= 0.0 && sum <= <$f>::EPSILON {
+                        sum.min(0.0)
+                    } else {
+                        sum
+                    }
+                }
+            }
+        }
         impl Round for $f {
             #[inline]
             fn floor(self) -> $f {
@@ -232,18 +366,125 @@ macro_rules! impl_ops {
                 <$f>::atan2(self, x)
             }
         }
+        impl Angle for $f {
+            #[inline]
+            fn frac_pi_2() -> $f {
+                std::$f::consts::FRAC_PI_2
+            }
+            #[inline]
+            fn to_degrees(self) -> $f {
+                <$f>::to_degrees(self)
+            }
+            #[inline]
+            fn to_radians(self) -> $f {
+                <$f>::to_radians(self)
+            }
+
+            #[inline]
+            fn wrap_radians(self) -> $f {
+                const TURN: $f = std::$f::consts::TAU;
+                let r = self.rem_euclid(TURN);
+                // `rem_euclid` rounds up to a full turn for tiny negative values.
+                // Adding zero also turns `-0.0` into `+0.0`.
+                if r < TURN {
+                    r + 0.0
+                } else {
+                    0.0
+                }
+            }
+            #[inline]
+            fn wrap_radians_signed(self) -> $f {
+                let r = Angle::wrap_radians(self);
+                if r > std::$f::consts::PI {
+                    r - std::$f::consts::TAU
+                } else {
+                    r
+                }
+            }
+            #[inline]
+            fn wrap_degrees(self) -> $f {
+                const TURN: $f = 360.0;
+                let r = self.rem_euclid(TURN);
+                if r < TURN {
+                    r + 0.0
+                } else {
+                    0.0
+                }
+            }
+            #[inline]
+            fn wrap_degrees_signed(self) -> $f {
+                let r = Angle::wrap_degrees(self);
+                if r > 180.0 {
+                    r - 360.0
+                } else {
+                    r
+                }
+            }
+            #[allow(clippy::float_cmp)]
+            fn sin_cos_degrees(self) -> ($f, $f) {
+                let d = Angle::wrap_degrees(self);
+                // Converting to radians rounds, so handle the exact cases manually.
+                if d == 0.0 {
+                    (0.0, 1.0)
+                } else if d == 90.0 {
+                    (1.0, 0.0)
+                } else if d == 180.0 {
+                    (0.0, -1.0)
+                } else if d == 270.0 {
+                    (-1.0, 0.0)
+                } else {
+                    Angle::wrap_degrees_signed(self).to_radians().sin_cos()
+                }
+            }
+        }
+        // the inherent `next_up` and `next_down` require a newer compiler,
+        // so this steps the bit pattern the same way.
+        impl NextFloat for $f {
+            #[inline]
+            fn next_up(self) -> $f {
+                if self.is_nan() || self == <$f>::INFINITY {
+                    self
+                } else if self == 0.0 {
+                    <$f>::from_bits(1)
+                } else if self > 0.0 {
+                    <$f>::from_bits(self.to_bits() + 1)
+                } else {
+                    <$f>::from_bits(self.to_bits() - 1)
+                }
+            }
+            #[inline]
+            fn next_down(self) -> $f {
+                -NextFloat::next_up(-self)
+            }
+        }
+        impl MulAdd for $f {
+            #[inline]
+            fn mul_add(self, a: $f, b: $f) -> $f {
+                <$f>::mul_add(self, a, b)
+            }
+        }
+        impl Consts for $f {
+            #[inline]
+            fn one() -> $f {
+                1.0
+            }
+            #[inline]
+            fn nan() -> $f {
+                <$f>::NAN
+            }
+        }
     };
-    ($($f: ty),*) => {
+    ($($f: ident),*) => {
         $(impl_ops!($f);)*
     }
 }
 
-#[cfg(not(feature = "num_traits"))]
+#[cfg(not(feature = "num"))]
 impl_ops!(f32, f64);
 
-#[cfg(feature = "num_traits")]
+#[cfg(feature = "num")]
 mod impl_num_traits {
-    use super::*;
+    use super::{Angle, Consts, Exp, MulAdd, NextFloat, Pow, Round, Signed, Trig};
     use num_traits::Float;
 
     impl<F: Float> crate::IsNan for F {
@@ -258,29 +499,101 @@ mod impl_num_traits {
             <F as Float>::is_finite(self)
         }
     }
+    impl<F: Float> crate::IsZero for F {
+        #[inline]
+        fn is_zero(self) -> bool {
+            <F as num_traits::Zero>::is_zero(&self)
+        }
+    }
+    impl<F: Float> crate::IsUnitInterval for F {
+        #[inline]
+        fn is_unit_interval(self) -> bool {
+            <F as Float>::is_sign_positive(self) && self <= F::one()
+        }
+        #[inline]
+        fn complement(self) -> Self {
+            F::one() - self
+        }
+    }
+    impl<F: Float> crate::IsSignedUnit for F {
+        #[inline]
+        fn is_signed_unit(self) -> bool {
+            <F as Float>::abs(self) <= F::one()
+        }
+    }
+    impl<F: Float> crate::IsSubnormal for F {
+        #[inline]
+        fn is_subnormal(self) -> bool {
+            <F as Float>::classify(self) == std::num::FpCategory::Subnormal
+        }
+        #[inline]
+        fn flush_to_zero(self) -> Self {
+            if crate::IsSubnormal::is_subnormal(self) {
+                self * F::zero()
+            } else {
+                self
+            }
+        }
+    }
+    impl<F: Float> crate::IsLogProbability for F {
+        #[inline]
+        fn is_log_probability(self) -> bool {
+            self <= F::zero()
+        }
+        #[inline]
+        fn log_complement(self) -> Self {
+            // Each branch avoids cancellation in the range where it's used.
+            if self > -F::from(std::f64::consts::LN_2).unwrap() {
+                Exp::ln(-Exp::exp_m1(self))
+            } else {
+                Exp::ln_1p(-Exp::exp(self))
+            }
+        }
+        #[inline]
+        fn log_add_exp(self, other: Self) -> Self {
+            let (max, min) = if self > other {
+                (self, other)
+            } else {
+                (other, self)
+            };
+            if min == F::neg_infinity() {
+                // avoid computing `-inf - -inf`
+                max
+            } else {
+                let sum = max + Exp::ln_1p(Exp::exp(min - max));
+                // for two log-probabilities such as `ln(p)` and `ln(1 - p)`, the sum can
+                // round to just above zero, so clamp anything within rounding error of it.
+                if max <= F::zero() && sum <= F::epsilon() {
+                    sum.min(F::zero())
+                } else {
+                    sum
+                }
+            }
+        }
+    }
     impl<F: Float> Round for F {
         #[inline]
         fn floor(self) -> Self {
-            <F as Float>::floor(self);
+            <F as Float>::floor(self)
         }
         #[inline]
         fn ceil(self) -> Self {
-            <F as Float>::ceil(self);
+            <F as Float>::ceil(self)
         }
         #[inline]
         fn round(self) -> Self {
-            <F as Float>::round(self);
+            <F as Float>::round(self)
         }
         #[inline]
         fn trunc(self) -> Self {
-            <F as Float>::trunc(self);
+            <F as Float>::trunc(self)
         }
         #[inline]
         fn fract(self) -> Self {
-            <F as Float>::fract(self);
+            <F as Float>::fract(self)
         }
     }
-    impl Signed for F {
+    impl<F: Float> Signed for F {
         #[inline]
         fn abs(self) -> Self {
             <F as Float>::abs(self)
@@ -321,7 +634,7 @@ mod impl_num_traits {
             <F as Float>::cbrt(self)
         }
         #[inline]
-        fn hypot(self, other: Self) {
+        fn hypot(self, other: Self) -> Self {
             <F as Float>::hypot(self, other)
         }
     }
@@ -336,7 +649,7 @@ mod impl_num_traits {
         }
         #[inline]
         fn exp_m1(self) -> Self {
-            <F as Float>::exmp_m1(self)
+            <F as Float>::exp_m1(self)
         }
 
         #[inline]
@@ -395,4 +708,109 @@ mod impl_num_traits {
             <F as Float>::atan2(self, x)
         }
     }
+
+    impl<F: Float + num_traits::FloatConst> Angle for F {
+        #[inline]
+        fn frac_pi_2() -> Self {
+            F::FRAC_PI_2()
+        }
+        #[inline]
+        fn to_degrees(self) -> Self {
+            <F as Float>::to_degrees(self)
+        }
+        #[inline]
+        fn to_radians(self) -> Self {
+            <F as Float>::to_radians(self)
+        }
+
+        #[inline]
+        fn wrap_radians(self) -> Self {
+            wrap(self, F::TAU())
+        }
+        #[inline]
+        fn wrap_radians_signed(self) -> Self {
+            wrap_signed(self, F::TAU())
+        }
+        #[inline]
+        fn wrap_degrees(self) -> Self {
+            wrap(self, degrees_turn())
+        }
+        #[inline]
+        fn wrap_degrees_signed(self) -> Self {
+            wrap_signed(self, degrees_turn())
+        }
+        fn sin_cos_degrees(self) -> (Self, Self) {
+            let quarter = degrees_turn::<F>() / (F::one() + F::one() + F::one() + F::one());
+            let d = Angle::wrap_degrees(self);
+            // Converting to radians rounds, so handle the exact cases manually.
+            if d == F::zero() {
+                (F::zero(), F::one())
+            } else if d == quarter {
+                (F::one(), F::zero())
+            } else if d == quarter + quarter {
+                (F::zero(), -F::one())
+            } else if d == quarter + quarter + quarter {
+                (-F::one(), F::zero())
+            } else {
+                Angle::wrap_degrees_signed(self).to_radians().sin_cos()
+            }
+        }
+    }
+    impl<F: Float> MulAdd for F {
+        #[inline]
+        fn mul_add(self, a: Self, b: Self) -> Self {
+            <F as Float>::mul_add(self, a, b)
+        }
+    }
+    impl<F: Float> Consts for F {
+        #[inline]
+        fn one() -> Self {
+            <F as num_traits::One>::one()
+        }
+        #[inline]
+        fn nan() -> Self {
+            <F as Float>::nan()
+        }
+    }
+    // `num_traits` has no way of stepping to an adjacent float,
+    // so this steps by at least one epsilon, which may skip over some numbers.
+    impl<F: Float> NextFloat for F {
+        #[inline]
+        fn next_up(self) -> Self {
+            if self.is_infinite() {
+                return self;
+            }
+            let step = (<F as Float>::abs(self) * F::epsilon()).max(F::min_positive_value());
+            self + step
+        }
+        #[inline]
+        fn next_down(self) -> Self {
+            if self.is_infinite() {
+                return self;
+            }
+            let step = (<F as Float>::abs(self) * F::epsilon()).max(F::min_positive_value());
+            self - step
+        }
+    }
+    fn degrees_turn<F: Float>() -> F {
+        F::from(360.0).unwrap()
+    }
+    fn wrap<F: Float>(val: F, turn: F) -> F {
+        let r = val % turn;
+        let r = if r < F::zero() { r + turn } else { r };
+        // Adding a turn rounds up to a full turn for tiny negative values.
+        if r < turn {
+            r + F::zero()
+        } else {
+            F::zero()
+        }
+    }
+    fn wrap_signed<F: Float>(val: F, turn: F) -> F {
+        let r = wrap(val, turn);
+        if r + r > turn {
+            r - turn
+        } else {
+            r
+        }
+    }
 }
@@ -0,0 +1,219 @@
+use std::{fmt, marker::PhantomData};
+
+use crate::{
+    constraint::{self, Constraint},
+    ops::Consts,
+    Checked, IsNan, ToOrd,
+};
+
+#[doc(hidden)]
+pub trait OptionFloat: IsNan + Consts {}
+impl<T> OptionFloat for T where T: IsNan + Consts {}
+
+/// An optional checked float, which is the same size as `F`.
+///
+/// `None` is stored as NaN, so the constraint `C` must never allow NaN,
+/// which is true for every built-in constraint.
+/// This is usually used through one of its aliases, such as [`OptionReal`].
+#[repr(transparent)]
+pub struct OptionChecked<F, C: Constraint<F>>(F, PhantomData<C>);
+
+/// An optional [`Real`](crate::Real), with `None` stored as NaN.
+pub type OptionReal<F> = OptionChecked<F, constraint::RealC>;
+/// An optional [`Finite`](crate::Finite), with `None` stored as NaN.
+pub type OptionFinite<F> = OptionChecked<F, constraint::FiniteC>;
+/// An optional [`NonNeg`](crate::NonNeg), with `None` stored as NaN.
+pub type OptionNonNeg<F> = OptionChecked<F, constraint::NonNegC>;
+
+impl<F: IsNan, C: Constraint<F>> OptionChecked<F, C> {
+    /// Creates an optional float that contains a value.
+    #[inline]
+    pub fn some(val: Checked<F, C>) -> Self {
+        Self(val.val(), PhantomData)
+    }
+    /// Converts this to an `Option`.
+    #[inline]
+    pub fn get(self) -> Option<Checked<F, C>> {
+        if self.0.is_nan() {
+            None
+        } else {
+            // SAFETY: the only way to store a value is through `Checked`,
+            // so any value that isn't the `None` niche upholds the constraint.
+            Some(unsafe { Checked::unchecked(self.0) })
+        }
+    }
+    /// Returns true if this contains a value.
+    #[inline]
+    pub fn is_some(self) -> bool {
+        !self.0.is_nan()
+    }
+    /// Returns true if this does not contain a value.
+    #[inline]
+    pub fn is_none(self) -> bool {
+        self.0.is_nan()
+    }
+    /// Gets the contained value.
+    /// # Panics
+    /// If this does not contain a value.
+    #[track_caller]
+    pub fn unwrap(self) -> Checked<F, C> {
+        match self.get() {
+            Some(val) => val,
+            None => crate::panic_display(&"called `unwrap` on a `None` value"),
+        }
+    }
+    /// Gets the contained value, or `default` if there is none.
+    #[inline]
+    pub fn unwrap_or(self, default: Checked<F, C>) -> Checked<F, C> {
+        self.get().unwrap_or(default)
+    }
+    /// Gets the contained value, or computes one from `f` if there is none.
+    #[inline]
+    pub fn unwrap_or_else(self, f: impl FnOnce() -> Checked<F, C>) -> Checked<F, C> {
+        self.get().unwrap_or_else(f)
+    }
+}
+
+impl<F: OptionFloat, C: Constraint<F>> OptionChecked<F, C> {
+    /// Creates an optional float that does not contain a value.
+    #[inline]
+    #[must_use]
+    pub fn none() -> Self {
+        Self(F::nan(), PhantomData)
+    }
+    /// Applies `f` to the contained value, if there is one.
+    #[inline]
+    pub fn map<C2: Constraint<F>>(
+        self,
+        f: impl FnOnce(Checked<F, C>) -> Checked<F, C2>,
+    ) -> OptionChecked<F, C2> {
+        self.get().map(f).into()
+    }
+    /// Takes the value out of this, leaving `None` in its place.
+    #[inline]
+    #[allow(clippy::return_self_not_must_use)]
+    pub fn take(&mut self) -> Self {
+        std::mem::take(self)
+    }
+    /// Replaces the contained value with `val`, returning the old value.
+    #[inline]
+    #[allow(clippy::return_self_not_must_use)]
+    pub fn replace(&mut self, val: Checked<F, C>) -> Self {
+        std::mem::replace(self, Self::some(val))
+    }
+}
+
+impl<F: Copy, C: Constraint<F>> Clone for OptionChecked<F, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<F: Copy, C: Constraint<F>> Copy for OptionChecked<F, C> {}
+
+impl<F: OptionFloat, C: Constraint<F>> Default for OptionChecked<F, C> {
+    #[inline]
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<F: IsNan + fmt::Debug, C: Constraint<F>> fmt::Debug for OptionChecked<F, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+impl<F: IsNan + ToOrd, C: Constraint<F>> PartialEq for OptionChecked<F, C> {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.get() == rhs.get()
+    }
+}
+impl<F: IsNan + ToOrd, C: Constraint<F>> Eq for OptionChecked<F, C> {}
+
+impl<F: IsNan, C: Constraint<F>> From<Checked<F, C>> for OptionChecked<F, C> {
+    #[inline]
+    fn from(val: Checked<F, C>) -> Self {
+        Self::some(val)
+    }
+}
+impl<F: OptionFloat, C: Constraint<F>> From<Option<Checked<F, C>>> for OptionChecked<F, C> {
+    #[inline]
+    fn from(val: Option<Checked<F, C>>) -> Self {
+        val.map_or_else(Self::none, Self::some)
+    }
+}
+impl<F: IsNan, C: Constraint<F>> From<OptionChecked<F, C>> for Option<Checked<F, C>> {
+    #[inline]
+    fn from(val: OptionChecked<F, C>) -> Self {
+        val.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Finite, NonNeg, Real};
+
+    macro_rules! some {
+        ($f: expr) => {
+            OptionReal::some(Real::new($f))
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "called `unwrap` on a `None` value")]
+    fn assert_unwrap_none() {
+        OptionReal::<f32>::none().unwrap();
+    }
+
+    #[test]
+    fn assert_size() {
+        use std::mem::size_of;
+        assert_eq!(size_of::<OptionReal<f32>>(), size_of::<f32>());
+        assert_eq!(size_of::<OptionFinite<f64>>(), size_of::<f64>());
+        assert_eq!(size_of::<OptionNonNeg<f32>>(), size_of::<f32>());
+    }
+
+    #[test]
+    fn assert_option() {
+        let a = some!(1.0f32);
+        assert!(a.is_some() && !a.is_none());
+        assert_eq!(a.get(), Some(Real::new(1.0)));
+        assert_eq!(a.unwrap(), Real::new(1.0));
+        // infinity is a valid `Real`, so it must not be mistaken for `None`.
+        assert!(some!(f32::INFINITY).is_some());
+
+        let none = OptionReal::<f32>::none();
+        assert!(none.is_none());
+        assert_eq!(none, OptionReal::default());
+        assert_eq!(none.get(), None);
+        assert_eq!(none.unwrap_or(Real::new(2.0)), Real::new(2.0));
+        assert_eq!(none.unwrap_or_else(|| Real::new(3.0)), Real::new(3.0));
+        assert_eq!(format!("{a:?} {none:?}"), "Some(Real(1.0)) None");
+    }
+
+    #[test]
+    fn assert_mutate() {
+        let mut a = some!(1.0f32);
+        assert_eq!(a.take(), some!(1.0));
+        assert!(a.is_none());
+        assert_eq!(a.replace(Real::new(2.0)), OptionReal::none());
+        assert_eq!(a, some!(2.0));
+
+        let b: OptionNonNeg<f32> =
+            OptionFinite::some(Finite::new(-2.0f32)).map(|x| NonNeg::new(x.val().abs()));
+        assert_eq!(b.get(), Some(NonNeg::new(2.0)));
+    }
+
+    #[test]
+    fn assert_convert() {
+        let a: OptionReal<f64> = Some(Real::new(1.0)).into();
+        assert_eq!(a, some!(1.0));
+        let b: OptionReal<f64> = None.into();
+        assert!(b.is_none());
+        assert_eq!(Option::from(a), Some(Real::new(1.0)));
+        assert_eq!(OptionReal::from(Real::new(1.0)), a);
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{
+    constraint::PositiveC, ops::Signed, Checked, IntoInner, IsNan, IsNegative, IsNonZero, NanError,
+    NonNeg, NonZero, Real,
+};
+
+/// The error produced when zero, a negative, or NaN value is encountered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PositiveError;
+impl std::fmt::Display for PositiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "encountered zero, a negative or NaN unexpectedly")
+    }
+}
+
+/// Trait for a floating point number that can be checked for zero (either `+0.0` or `-0.0`).
+pub trait IsZero: Sized + Copy {
+    fn is_zero(self) -> bool;
+}
+
+#[doc(hidden)]
+pub trait IsPositive: crate::IsNegative + IsZero {}
+impl<T: Signed + crate::IsNan + IsZero> IsPositive for T {}
+
+/// A floating point number that is strictly greater than zero.
+///
+/// Positive infinity is allowed, just like with [`NonNeg`](crate::NonNeg).
+pub type Positive<F> = Checked<F, PositiveC>;
+
+// rounding is not defined, since it could produce zero
+signed_impls!(Positive<F: IsPositive>);
+sum_impls!(
+    Positive<F: IsPositive>,
+    PositiveError,
+    "If the result is zero, negative or NaN."
+);
+// neg produces a `Negative`, and is defined in `negative.rs`
+product_impls!(
+    Positive<F: IsPositive>,
+    PositiveError,
+    "If the result is zero, negative or NaN (caused by underflow)."
+);
+impl<F: IsPositive + crate::ops::Pow> Positive<F> {
+    pow_methods!(
+        F,
+        PositiveError,
+        "If the result is zero (caused by underflow) or NaN."
+    );
+    recip_methods!(
+        F,
+        PositiveError,
+        "If the result is zero (caused if the input is `infinity`)."
+    );
+    sqrt_methods!(F);
+    cbrt_methods!(F);
+    hypot_methods!(F, PositiveError, "If the result is NaN.");
+}
+exp_impls!(
+    Positive<F: IsPositive>,
+    PositiveError,
+    "If the result is zero, negative or NaN."
+);
+impl<F: IsPositive + crate::ops::Exp> Positive<F> {
+    /// Attempts to find the log base `b` of `self`, which may be negative.
+    /// # Errors
+    /// If the result is NaN (caused if `b` is negative or one).
+    pub fn try_log_real(self, b: impl IntoInner<F>) -> Result<Real<F>, NanError> {
+        let val = self.val().log(b.into_inner());
+        Real::try_new(val)
+    }
+    /// Computes the natural log (base e) of `self`, which may be negative.
+    #[must_use]
+    pub fn ln_real(self) -> Real<F> {
+        // the log of a positive number is never NaN
+        unsafe { Real::unchecked(self.val().ln()) }
+    }
+    /// Computes the log base 2 of `self`, which may be negative.
+    #[must_use]
+    pub fn log2_real(self) -> Real<F> {
+        unsafe { Real::unchecked(self.val().log2()) }
+    }
+    /// Computes the log base 10 of `self`, which may be negative.
+    #[must_use]
+    pub fn log10_real(self) -> Real<F> {
+        unsafe { Real::unchecked(self.val().log10()) }
+    }
+}
+
+impl<F: IsPositive> Positive<F> {
+    /// Adds a positive and a non-negative number.
+    #[must_use]
+    pub fn add_positive(self, rhs: impl Into<NonNeg<F>>) -> Self
+    where
+        F: ::core::ops::Add<Output = F>,
+    {
+        unsafe { Self::unchecked(self.val() + rhs.into().val()) }
+    }
+    /// Multiplies two positive numbers, where the product may underflow to zero.
+    #[must_use]
+    pub fn mul_underflowing(self, rhs: Self) -> NonNeg<F>
+    where
+        F: ::core::ops::Mul<Output = F>,
+    {
+        unsafe { NonNeg::unchecked(self.val() * rhs.val()) }
+    }
+}
+impl<F: IsPositive> NonNeg<F> {
+    /// Adds a non-negative and a positive number.
+    #[must_use]
+    pub fn add_positive(self, rhs: Positive<F>) -> Positive<F>
+    where
+        F: ::core::ops::Add<Output = F>,
+    {
+        unsafe { Positive::unchecked(self.val() + rhs.val()) }
+    }
+}
+
+convert_impls!(
+    Positive<F: IsPositive>,
+    PositiveError => NonNeg<F: IsNegative>,
+    NonZero<F: IsNonZero>,
+    Real<F: IsNan>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! pos {
+        ($f: expr) => {
+            Positive::new($f)
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered zero, a negative or NaN unexpectedly")]
+    fn assert_new_nan() {
+        pos!(f32::NAN);
+    }
+    #[test]
+    #[should_panic(expected = "encountered zero, a negative or NaN unexpectedly")]
+    fn assert_new_zero() {
+        pos!(0.0f32);
+    }
+    #[test]
+    #[should_panic(expected = "encountered zero, a negative or NaN unexpectedly")]
+    fn assert_new_zero2() {
+        pos!(-0.0f32);
+    }
+    #[test]
+    #[should_panic(expected = "encountered zero, a negative or NaN unexpectedly")]
+    fn assert_new_neg() {
+        pos!(-1.0f32);
+    }
+
+    #[test]
+    fn unchecked() {
+        let pos = unsafe { Positive::unchecked(-1.0f32) };
+        assert!(pos.val().is_sign_negative());
+    }
+
+    #[test]
+    fn assert_nan() {
+        assert_err!(pos!(1.0f32).try_sub(1.0));
+        assert_err!(pos!(1.0f32).try_add(-2.0));
+        assert_err!(pos!(1.0f32).try_mul(0.0));
+        assert_err!(pos!(f32::MIN_POSITIVE).try_mul(f32::MIN_POSITIVE)); // underflows to zero
+        assert_err!(pos!(1.0f32).try_div(f32::INFINITY));
+        assert_err!(pos!(2.0f32).try_rem(1.0));
+
+        assert_err!(pos!(f32::INFINITY).try_recip());
+        assert_err!(pos!(2.0f32).try_powi(-1000));
+
+        assert_err!(pos!(0.5f32).try_ln());
+        assert_err!(pos!(1.0f32).try_log2());
+        assert_err!(pos!(1.0f32).try_log_real(1.0));
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(pos!(2.0f32) + 1.0, pos!(3.0));
+        assert_eq!(pos!(2.0f32) - 1.0, pos!(1.0));
+        assert_eq!(pos!(5.0f32) * 2.0, pos!(10.0));
+        assert_eq!(pos!(8.0f32) / 2.0, pos!(4.0));
+        assert_eq!(Real::new(8.0f32) / pos!(2.0), Real::new(4.0));
+    }
+
+    #[test]
+    fn assert_pow() {
+        assert_eq!(pos!(4.0f32).powf(3.5), pos!(128.0));
+        assert_eq!(pos!(2.0f32).powi(8), pos!(256.0));
+        assert_eq!(pos!(2.0f32).recip(), pos!(0.5));
+        assert_eq!(pos!(4.0f32).sqrt(), pos!(2.0));
+        assert_eq!(pos!(27.0f32).cbrt(), pos!(3.0));
+    }
+
+    #[test]
+    fn assert_exp() {
+        // `Positive` cannot represent the difference of two equal values.
+        assert_epsilon!(pos!(2.0f32).exp().val(), 7.389_056);
+        assert_epsilon!(pos!(3.0f32).exp2().val(), 8.0);
+        assert_epsilon!(pos!(5.0f32).exp_m1().val(), 147.413_16);
+        assert_epsilon!(pos!(8.0f32).log2().val(), 3.0);
+        assert_epsilon!(pos!(0.5f32).ln_real(), Real::new(-std::f32::consts::LN_2));
+        assert_epsilon!(pos!(0.125f32).log2_real(), Real::new(-3.0));
+        assert_epsilon!(pos!(0.001f32).log10_real(), Real::new(-3.0));
+    }
+
+    #[test]
+    fn assert_precise_output() {
+        assert_eq!(
+            pos!(1.0f32).add_positive(pos!(f32::INFINITY)),
+            pos!(f32::INFINITY)
+        );
+        let tiny = pos!(f32::MIN_POSITIVE);
+        assert_eq!(tiny.mul_underflowing(tiny), NonNeg::new(0.0));
+        assert_eq!(
+            pos!(f32::INFINITY).mul_underflowing(tiny),
+            NonNeg::new(f32::INFINITY)
+        );
+
+        // mixed pairs
+        assert_eq!(pos!(1.0f32).add_positive(NonNeg::new(0.0)), pos!(1.0));
+        assert_eq!(NonNeg::new(0.0f32).add_positive(pos!(1.0)), pos!(1.0));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{
+    constraint::LogProbabilityC, ops::Exp, Checked, IsNan, IsPositive, IsUnitInterval, NonPos,
+    Real, UnitInterval,
+};
+
+/// The error produced when a value greater than zero, or NaN, is encountered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogProbabilityError;
+impl std::fmt::Display for LogProbabilityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "encountered a value greater than zero or NaN unexpectedly"
+        )
+    }
+}
+
+/// Trait for a floating point number that can be checked for being a log-probability, `<= 0`.
+pub trait IsLogProbability: Exp {
+    /// Returns true if `self <= 0.0`, including `+0.0`.
+    fn is_log_probability(self) -> bool;
+    /// Computes `ln(1 - exp(self))`, without losing precision for values near zero.
+    #[must_use]
+    fn log_complement(self) -> Self;
+    /// Computes `ln(exp(self) + exp(other))`, without losing precision for large negative values.
+    ///
+    /// If both inputs are at most zero, a result that is above zero only due to rounding error
+    /// is clamped to zero.
+    #[must_use]
+    fn log_add_exp(self, other: Self) -> Self;
+}
+
+/// A probability in the range `[0, 1]`.
+///
+/// This is the same type as [`UnitInterval`], which already has the operations needed for
+/// linear-space probabilities: multiplication is infallible, and `1 - p` is provided by
+/// [`complement`](UnitInterval::complement).
+pub type Probability<F> = UnitInterval<F>;
+
+/// The natural logarithm of a probability, in the range `[-inf, 0]`.
+///
+/// Multiplying two probabilities corresponds to adding their logarithms,
+/// so `+` is infallible for this type.
+pub type LogProbability<F> = Checked<F, LogProbabilityC>;
+
+impl<F: IsUnitInterval + IsLogProbability> Probability<F> {
+    /// Converts this probability into log-space.
+    #[must_use]
+    pub fn to_log(self) -> LogProbability<F> {
+        // the logarithm of a value in `[0, 1]` is always in `[-inf, 0]`.
+        unsafe { LogProbability::unchecked(self.val().ln()) }
+    }
+}
+impl<F: IsUnitInterval + IsLogProbability> LogProbability<F> {
+    /// Converts this log-probability back into linear space.
+    #[must_use]
+    pub fn to_probability(self) -> Probability<F> {
+        unsafe { Probability::unchecked(self.val().exp()) }
+    }
+}
+impl<F: IsUnitInterval + IsLogProbability> From<Probability<F>> for LogProbability<F> {
+    #[inline]
+    fn from(val: Probability<F>) -> Self {
+        val.to_log()
+    }
+}
+impl<F: IsUnitInterval + IsLogProbability> From<LogProbability<F>> for Probability<F> {
+    #[inline]
+    fn from(val: LogProbability<F>) -> Self {
+        val.to_probability()
+    }
+}
+
+convert_impls!(
+    LogProbability<F: IsLogProbability>,
+    LogProbabilityError => NonPos<F: IsPositive>,
+    Real<F: IsNan>
+);
+
+impl<F: IsLogProbability> LogProbability<F> {
+    /// Computes the logarithm of `1 - p`, where `p` is the probability represented by self.
+    #[must_use]
+    pub fn complement(self) -> Self {
+        unsafe { Self::unchecked(self.val().log_complement()) }
+    }
+    /// Attempts to compute the logarithm of `p + q`,
+    /// where `p` and `q` are the probabilities represented by `self` and `other`.
+    /// # Errors
+    /// If the result is greater than zero (caused if `p + q > 1` by more than rounding error).
+    pub fn try_log_add_exp(self, other: Self) -> Result<Self, LogProbabilityError> {
+        Self::try_new(self.val().log_add_exp(other.val()))
+    }
+    /// Computes the logarithm of `p + q`,
+    /// where `p` and `q` are the probabilities represented by `self` and `other`.
+    /// # Panics
+    /// If the result is greater than zero (caused if `p + q > 1` by more than rounding error).
+    #[track_caller]
+    #[must_use]
+    pub fn log_add_exp(self, other: Self) -> Self {
+        Self::new(self.val().log_add_exp(other.val()))
+    }
+}
+
+impl<F: IsLogProbability + ::core::ops::Add<Output = F>> ::core::ops::Add for LogProbability<F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        // the sum of two non-positive values is never positive.
+        // it can't be NaN either, since positive infinity is not allowed.
+        unsafe { Self::unchecked(self.val() + rhs.val()) }
+    }
+}
+impl<F: IsLogProbability + ::core::ops::Add<Output = F>> ::core::ops::AddAssign
+    for LogProbability<F>
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! prob {
+        ($f: expr) => {
+            Probability::new($f)
+        };
+    }
+    macro_rules! lp {
+        ($f: expr) => {
+            LogProbability::new($f)
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered a value greater than zero or NaN unexpectedly")]
+    fn assert_new_nan() {
+        lp!(f32::NAN);
+    }
+    #[test]
+    #[should_panic(expected = "encountered a value greater than zero or NaN unexpectedly")]
+    fn assert_new_pos() {
+        lp!(f32::EPSILON);
+    }
+
+    #[test]
+    fn assert_bounds() {
+        assert_eq!(lp!(0.0f32), lp!(-0.0));
+        assert_eq!(lp!(f32::NEG_INFINITY).to_probability(), prob!(0.0));
+        assert_eq!(prob!(0.0f32).to_log(), lp!(f32::NEG_INFINITY));
+        assert_eq!(prob!(1.0f32).to_log(), lp!(0.0));
+    }
+
+    #[test]
+    fn assert_convert() {
+        let p = prob!(0.25f32);
+        assert_epsilon!(LogProbability::from(p).val(), 0.25f32.ln());
+        assert_epsilon!(Probability::from(LogProbability::from(p)), p);
+        assert_eq!(NonPos::from(lp!(-1.0f32)), NonPos::new(-1.0));
+        assert_eq!(
+            Real::from(lp!(f32::NEG_INFINITY)),
+            Real::new(f32::NEG_INFINITY)
+        );
+        assert_eq!(LogProbability::try_from(Real::new(-2.0f32)), Ok(lp!(-2.0)));
+        assert_eq!(
+            LogProbability::try_from(Real::new(1.0f32)),
+            Err(LogProbabilityError)
+        );
+    }
+
+    #[test]
+    fn assert_ops() {
+        // adding log-probabilities multiplies the probabilities.
+        assert_epsilon!(
+            (prob!(0.5f32).to_log() + prob!(0.5).to_log()).to_probability(),
+            prob!(0.25)
+        );
+        assert_eq!(lp!(f32::NEG_INFINITY) + lp!(-1.0), lp!(f32::NEG_INFINITY));
+
+        assert_epsilon!(
+            prob!(0.25f32)
+                .to_log()
+                .log_add_exp(prob!(0.5).to_log())
+                .to_probability(),
+            prob!(0.75)
+        );
+        assert_eq!(lp!(f32::NEG_INFINITY).log_add_exp(lp!(-1.0)), lp!(-1.0));
+        assert_eq!(
+            lp!(f32::NEG_INFINITY).log_add_exp(lp!(f32::NEG_INFINITY)),
+            lp!(f32::NEG_INFINITY)
+        );
+        // this would underflow to `-inf` if it were computed in linear space.
+        assert_epsilon!(
+            lp!(-1000.0f32).log_add_exp(lp!(-1000.0)).val(),
+            -1000.0 + 2.0f32.ln(),
+            1e-3
+        );
+        assert_err!(lp!(-0.1f32).try_log_add_exp(lp!(-0.1)));
+    }
+
+    #[test]
+    fn assert_log_add_exp_complement() {
+        // `p + (1 - p)` used to round to slightly more than one in log-space.
+        for i in 1..1000 {
+            let p = prob!(f64::from(i) / 1000.0);
+            let q = p.complement();
+            assert!(p.to_log().try_log_add_exp(q.to_log()).is_ok());
+
+            #[allow(clippy::cast_possible_truncation)]
+            let (p, q) = (prob!(p.val() as f32), prob!(q.val() as f32));
+            assert!(p.to_log().try_log_add_exp(q.to_log()).is_ok());
+        }
+    }
+
+    #[test]
+    fn assert_complement() {
+        assert_epsilon!(
+            prob!(0.25f32).to_log().complement().to_probability(),
+            prob!(0.75)
+        );
+        assert_epsilon!(
+            prob!(0.75f32).to_log().complement().to_probability(),
+            prob!(0.25)
+        );
+        assert_eq!(lp!(0.0f32).complement(), lp!(f32::NEG_INFINITY));
+        assert_eq!(lp!(f32::NEG_INFINITY).complement(), lp!(0.0));
+        // `1 - exp(x)` would round to zero here, since `exp(x)` is so close to one.
+        assert_epsilon!(lp!(-1e-10f32).complement().val(), 1e-10f32.ln(), 1e-4);
+    }
+}
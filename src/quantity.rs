@@ -0,0 +1,215 @@
+use std::{fmt, marker::PhantomData};
+
+use crate::{
+    unit::{Equivalent, Per, Prod, Unit},
+    IntoInner,
+};
+
+/// A checked floating point number `T`, measured in the unit `U`.
+///
+/// Every operation is forwarded to `T`, so the invariant of the scalar is checked
+/// just like it would be without units: for example, the sum of two
+/// `Quantity<NonNeg<f64>, Seconds>` is still non-negative.
+/// Quantities can only be added or compared if they have the same unit.
+#[repr(transparent)]
+pub struct Quantity<T, U: Unit>(T, PhantomData<U>);
+
+impl<T, U: Unit> Quantity<T, U> {
+    /// Creates a new quantity, measured in the unit `U`.
+    #[inline]
+    pub const fn new(val: T) -> Self {
+        Self(val, PhantomData)
+    }
+    /// Gets the scalar value of this quantity, discarding the unit.
+    #[inline]
+    pub fn get(self) -> T {
+        self.0
+    }
+    /// Gets the inner floating point value of this quantity, discarding the unit.
+    #[inline]
+    pub fn val<F>(self) -> F
+    where
+        T: IntoInner<F>,
+    {
+        self.0.into_inner()
+    }
+    /// Converts this quantity to an equivalent unit, such as from `(m/s)·s` to `m`.
+    #[inline]
+    pub fn into_unit<V: Unit>(self) -> Quantity<T, V>
+    where
+        U: Equivalent<V>,
+    {
+        Quantity::new(self.0)
+    }
+    /// Multiplies this quantity by a unitless scalar.
+    #[track_caller]
+    pub fn scale<S>(self, rhs: S) -> Quantity<T::Output, U>
+    where
+        T: ::core::ops::Mul<S>,
+    {
+        Quantity::new(self.0 * rhs)
+    }
+}
+
+impl<T: Clone, U: Unit> Clone for Quantity<T, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+impl<T: Copy, U: Unit> Copy for Quantity<T, U> {}
+
+impl<T: Default, U: Unit> Default for Quantity<T, U> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: fmt::Debug, U: Unit> fmt::Debug for Quantity<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} ", self.0)?;
+        U::fmt_symbol(f)
+    }
+}
+
+impl<T: PartialEq, U: Unit> PartialEq for Quantity<T, U> {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.0 == rhs.0
+    }
+}
+impl<T: Eq, U: Unit> Eq for Quantity<T, U> {}
+impl<T: PartialOrd, U: Unit> PartialOrd for Quantity<T, U> {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(&rhs.0)
+    }
+}
+impl<T: Ord, U: Unit> Ord for Quantity<T, U> {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&rhs.0)
+    }
+}
+
+impl<T: ::core::ops::Add<Output = T>, U: Unit> ::core::ops::Add for Quantity<T, U> {
+    type Output = Self;
+    #[track_caller]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.0 + rhs.0)
+    }
+}
+impl<T: ::core::ops::Sub<Output = T>, U: Unit> ::core::ops::Sub for Quantity<T, U> {
+    type Output = Self;
+    #[track_caller]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.0 - rhs.0)
+    }
+}
+impl<T: ::core::ops::Add<Output = T> + Copy, U: Unit> ::core::ops::AddAssign for Quantity<T, U> {
+    #[track_caller]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+impl<T: ::core::ops::Sub<Output = T> + Copy, U: Unit> ::core::ops::SubAssign for Quantity<T, U> {
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+impl<T: ::core::ops::Neg, U: Unit> ::core::ops::Neg for Quantity<T, U> {
+    type Output = Quantity<T::Output, U>;
+    #[track_caller]
+    fn neg(self) -> Self::Output {
+        Quantity::new(-self.0)
+    }
+}
+
+impl<T: ::core::ops::Mul<T2>, T2, A: Unit, B: Unit> ::core::ops::Mul<Quantity<T2, B>>
+    for Quantity<T, A>
+{
+    type Output = Quantity<T::Output, Prod<A, B>>;
+    #[track_caller]
+    fn mul(self, rhs: Quantity<T2, B>) -> Self::Output {
+        Quantity::new(self.0 * rhs.0)
+    }
+}
+impl<T: ::core::ops::Div<T2>, T2, A: Unit, B: Unit> ::core::ops::Div<Quantity<T2, B>>
+    for Quantity<T, A>
+{
+    type Output = Quantity<T::Output, Per<A, B>>;
+    #[track_caller]
+    fn div(self, rhs: Quantity<T2, B>) -> Self::Output {
+        Quantity::new(self.0 / rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        unit::{Meters, Seconds},
+        Finite, NonNeg, NonPos,
+    };
+
+    macro_rules! meters {
+        ($f: expr) => {
+            Quantity::<_, Meters>::new(Finite::new($f))
+        };
+    }
+    macro_rules! seconds {
+        ($f: expr) => {
+            Quantity::<_, Seconds>::new(NonNeg::new($f))
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered infinity or NaN unexpectedly")]
+    fn assert_add_inf() {
+        let _ = meters!(f64::MAX) + meters!(f64::MAX);
+    }
+    #[test]
+    #[should_panic(expected = "encountered a negative or NaN unexpectedly")]
+    fn assert_sub_neg() {
+        let _ = seconds!(1.0f64) - seconds!(2.0);
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(meters!(1.0f64) + meters!(2.0), meters!(3.0));
+        assert_eq!(meters!(1.0f64) - meters!(2.0), meters!(-1.0));
+        assert_eq!(meters!(1.5f64).scale(2.0), meters!(3.0));
+        assert!(meters!(1.0f64) < meters!(2.0));
+
+        let mut time = seconds!(1.0f64);
+        time += seconds!(0.5);
+        assert_eq!(time, seconds!(1.5));
+        let neg: Quantity<NonPos<f64>, Seconds> = -time;
+        assert_eq!(neg.get(), NonPos::new(-1.5));
+        assert!(neg.val::<f64>().is_sign_negative());
+    }
+
+    #[test]
+    fn assert_units() {
+        let speed: Quantity<Finite<f64>, Per<Meters, Seconds>> = meters!(10.0f64) / seconds!(4.0);
+        assert_eq!(speed.get(), Finite::new(2.5));
+        let area: Quantity<Finite<f64>, Prod<Meters, Meters>> = meters!(2.0f64) * meters!(3.0);
+        assert_eq!(area.get(), Finite::new(6.0));
+
+        assert_eq!(format!("{speed:?}"), "Finite(2.5) m/s");
+        assert_eq!(format!("{area:?}"), "Finite(6.0) m·m");
+    }
+
+    #[test]
+    fn assert_into_unit() {
+        let speed = meters!(10.0f64) / seconds!(4.0);
+        let distance: Quantity<Finite<f64>, Meters> = (speed * seconds!(2.0)).into_unit();
+        assert_eq!(distance + meters!(1.0), meters!(6.0));
+        let distance = (seconds!(2.0) * speed).into_unit::<Meters>();
+        assert_eq!(distance.get(), NonNeg::new(5.0));
+
+        let area = meters!(2.0f64) * seconds!(3.0);
+        assert_eq!((area / seconds!(3.0)).into_unit::<Meters>(), meters!(2.0));
+    }
+}
@@ -1,7 +1,7 @@
-use crate::IntoInner;
+use crate::{constraint::RealC, Checked, IsNegative, NonNeg};
 
 /// The error produced when NaN is encountered.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NanError;
 impl std::fmt::Display for NanError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -14,64 +14,33 @@ pub trait IsNan: Sized + Copy {
     fn is_nan(self) -> bool;
 }
 
-#[derive(Debug, Clone, Copy, Default)]
-#[repr(transparent)]
-pub struct Real<F: IsNan>(F);
-
-impl<F: IsNan> Real<F> {
-    /// Attempts to create a new `Real` float.
-    /// # Errors
-    /// If the value is NaN.
-    pub fn try_new(val: F) -> Result<Self, NanError> {
-        if val.is_nan() {
-            Err(NanError)
-        } else {
-            Ok(Self(val))
-        }
-    }
-    /// Const-safe constructor for `Real` that never checks the value.
-    /// # Safety
-    /// Ensure that the value can never be `NaN`.
-    pub const unsafe fn unchecked(val: F) -> Self {
-        union Transmute<F: IsNan> {
-            val: F,
-            real: Real<F>,
-        }
-
-        // SAFETY: `Real` is `repr(transparent)`.
-        Transmute { val }.real
-    }
-    /// Gets the inner value of this number.
-    #[inline]
-    pub const fn val(self) -> F {
-        self.0
-    }
-}
+/// A floating point number that is never NaN.
+pub type Real<F> = Checked<F, RealC>;
 
-ctor_impls!(Real<F: IsNan>, "If the number is NaN.");
-
-impl<F: IsNan> IntoInner<F> for Real<F> {
-    #[inline]
-    fn into_inner(self) -> F {
-        self.val()
+impl<F: IsNan + Default> Default for Real<F> {
+    fn default() -> Self {
+        Self::new(F::default())
     }
 }
 
-eq_impls!(Real<F: IsNan>);
-ord_impls!(Real<F: IsNan>);
 round_impls!(Real<F: IsNan>);
-signed_impls!(Real<F: IsNan>);
+signed_impls!(Real<F: IsNan>, abs -> NonNeg<F: IsNegative>);
 sum_impls!(Real<F: IsNan>, NanError, "If the result is NaN.");
 neg_impls!(Real<F: IsNan>, NanError, "If the result is NaN.");
 product_impls!(Real<F: IsNan>, NanError, "If the result is NaN.");
 impl<F: IsNan + crate::ops::Pow> Real<F> {
-    pow_methods!(F, NanError, "If the result is NaN.");
+    pow_methods!(F, NanError, "If the result is NaN.", -> NonNeg<F: IsNegative>);
     recip_methods!(F); // recip is infallible for real numbers
-    sqrt_methods!(F, NanError, "If the result is NaN.");
+    sqrt_methods!(F, NanError, "If the result is NaN.", -> NonNeg<F: IsNegative>);
     cbrt_methods!(F);
-    hypot_methods!(F, NanError, "If the result is NaN.");
+    hypot_methods!(F, NanError, "If the result is NaN.", -> NonNeg<F: IsNegative>);
 }
-exp_impls!(Real<F: IsNan>, NanError, "If the result is NaN.");
+exp_impls!(
+    Real<F: IsNan>,
+    NanError,
+    "If the result is NaN.",
+    -> NonNeg<F: IsNegative>
+);
 impl<F: IsNan + crate::ops::Trig> Real<F> {
     sin_cos_methods!(
         F,
@@ -103,12 +72,12 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "encountered NaN unexpectedly")]
     fn assert_new_nan() {
         real!(f32::NAN);
     }
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "encountered NaN unexpectedly")]
     fn assert_new_nan2() {
         real!(-f32::NAN);
     }
@@ -151,7 +120,7 @@ mod tests {
 
     #[test]
     #[allow(clippy::bool_assert_comparison)]
-    #[allow(clippy::cmp_nan)]
+    #[allow(invalid_nan_comparisons)]
     fn assert_cmp_weird() {
         assert!(real!(f32::NEG_INFINITY) < real!(-1.0));
         assert!(real!(-1.0f32) < real!(0.0));
@@ -190,6 +159,28 @@ mod tests {
         assert_epsilon!(real!(147.413_16f32).ln_1p(), real!(5.0));
     }
 
+    #[test]
+    fn assert_non_neg_output() {
+        let abs: NonNeg<f32> = real!(-2.0f32).abs();
+        assert_eq!(abs, NonNeg::new(2.0));
+        let sqrt: NonNeg<f32> = real!(-0.0f32).sqrt();
+        assert!(sqrt.is_sign_positive());
+        let exp: NonNeg<f32> = real!(f32::NEG_INFINITY).exp();
+        assert_eq!(exp, NonNeg::new(0.0));
+        let hypot: NonNeg<f32> = real!(-3.0f32).hypot(4.0);
+        assert_eq!(hypot, NonNeg::new(5.0));
+        let square: NonNeg<f32> = real!(-3.0f32).square_powi(1);
+        assert_eq!(square, NonNeg::new(9.0));
+        assert_eq!(real!(2.0f32).square_powi(3), NonNeg::new(64.0));
+        // `2 * n` would overflow an `i32`.
+        assert_eq!(real!(-1.0f32).square_powi(i32::MAX), NonNeg::new(1.0));
+        assert_eq!(
+            real!(0.5f32).square_powi(i32::MIN),
+            NonNeg::new(f32::INFINITY)
+        );
+        assert_eq!(real!(-2.0f32).square_powi(i32::MIN), NonNeg::new(0.0));
+    }
+
     #[test]
     fn assert_trig() {
         use std::f32::consts::{FRAC_1_SQRT_2, PI};
@@ -0,0 +1,181 @@
+use crate::{
+    constraint::SignedUnitC, Checked, Finite, IsFinite, IsNan, IsUnitInterval, Real, UnitInterval,
+};
+
+/// The error produced when a value outside of `[-1, 1]` or NaN is encountered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignedUnitError;
+impl std::fmt::Display for SignedUnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "encountered a value outside of [-1, 1] or NaN unexpectedly"
+        )
+    }
+}
+
+/// Trait for a floating point number that can be checked for being within `[-1, 1]`.
+pub trait IsSignedUnit: Sized + Copy {
+    /// Returns true if `-1.0 <= self <= 1.0`.
+    fn is_signed_unit(self) -> bool;
+}
+
+/// A floating point number in the interval `[-1, 1]`, such as the sine or cosine of an angle.
+pub type SignedUnit<F> = Checked<F, SignedUnitC>;
+
+impl<F: IsSignedUnit + Default> Default for SignedUnit<F> {
+    fn default() -> Self {
+        Self::new(F::default())
+    }
+}
+
+round_impls!(SignedUnit<F: IsSignedUnit>);
+signed_impls!(SignedUnit<F: IsSignedUnit>, abs -> UnitInterval<F: IsUnitInterval>);
+sum_impls!(
+    SignedUnit<F: IsSignedUnit>,
+    SignedUnitError,
+    "If the result is outside of `[-1, 1]` or NaN."
+);
+neg_impls!(SignedUnit<F: IsSignedUnit>); // the interval is symmetric, so negation always succeeds
+closed_product_impls!(
+    SignedUnit<F: IsSignedUnit>,
+    SignedUnitError,
+    "If the result is outside of `[-1, 1]` or NaN."
+);
+impl<F: IsSignedUnit + crate::ops::Pow> SignedUnit<F> {
+    pow_methods!(
+        F,
+        SignedUnitError,
+        "If the result is outside of `[-1, 1]` or NaN.",
+        -> UnitInterval<F: IsUnitInterval>
+    );
+    sqrt_methods!(
+        F,
+        SignedUnitError,
+        "If the result is NaN (caused if the input is negative).",
+        -> UnitInterval<F: IsUnitInterval>
+    );
+    cbrt_methods!(F);
+}
+impl<F: IsSignedUnit + IsFinite + crate::ops::Trig> SignedUnit<F> {
+    /// Computes the arcsine of a number, in the range `[-pi/2, pi/2]`.
+    #[must_use]
+    pub fn asin(self) -> Finite<F> {
+        // asin is defined for every value in `[-1, 1]`
+        unsafe { Finite::unchecked(self.val().asin()) }
+    }
+    /// Computes the arccosine of a number, in the range `[0, pi]`.
+    #[must_use]
+    pub fn acos(self) -> Finite<F> {
+        unsafe { Finite::unchecked(self.val().acos()) }
+    }
+}
+
+impl<F: IsFinite + IsSignedUnit + crate::ops::Trig> Finite<F> {
+    /// Computes the sine of a number (in radians), which always falls within `[-1, 1]`.
+    #[must_use]
+    pub fn sin_unit(self) -> SignedUnit<F> {
+        unsafe { SignedUnit::unchecked(self.val().sin()) }
+    }
+    /// Computes the cosine of a number (in radians), which always falls within `[-1, 1]`.
+    #[must_use]
+    pub fn cos_unit(self) -> SignedUnit<F> {
+        unsafe { SignedUnit::unchecked(self.val().cos()) }
+    }
+    /// Computes the sine and cosine of a number (in radians) simultaneously.
+    #[must_use]
+    pub fn sin_cos_unit(self) -> (SignedUnit<F>, SignedUnit<F>) {
+        let (s, c) = self.val().sin_cos();
+        unsafe { (SignedUnit::unchecked(s), SignedUnit::unchecked(c)) }
+    }
+}
+
+convert_impls!(
+    SignedUnit<F: IsSignedUnit>,
+    SignedUnitError => Finite<F: IsFinite>,
+    Real<F: IsNan>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! unit {
+        ($f: expr) => {
+            SignedUnit::new($f)
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered a value outside of [-1, 1] or NaN unexpectedly")]
+    fn assert_new_nan() {
+        unit!(f32::NAN);
+    }
+    #[test]
+    #[should_panic(expected = "encountered a value outside of [-1, 1] or NaN unexpectedly")]
+    fn assert_new_big() {
+        unit!(1.0f32 + f32::EPSILON);
+    }
+    #[test]
+    #[should_panic(expected = "encountered a value outside of [-1, 1] or NaN unexpectedly")]
+    fn assert_new_small() {
+        unit!(-1.0f32 - f32::EPSILON);
+    }
+
+    #[test]
+    fn unchecked() {
+        let unit = unsafe { SignedUnit::unchecked(-2.0f32) };
+        assert!(unit.val() < -1.0);
+    }
+
+    #[test]
+    fn assert_nan() {
+        assert_err!(unit!(0.5f32).try_add(0.75));
+        assert_err!(unit!(-0.5f32).try_sub(0.75));
+        assert_err!(unit!(0.5f32).try_mul(3.0));
+        assert_err!(unit!(0.5f32).try_div(0.25));
+        assert_err!(unit!(-0.25f32).try_sqrt());
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(unit!(0.5f32) + 0.25, unit!(0.75));
+        assert_eq!(unit!(0.5f32) - 1.0, unit!(-0.5));
+        assert_eq!(unit!(-0.5f32) * unit!(0.5), unit!(-0.25));
+        assert_eq!(-unit!(1.0f32), unit!(-1.0));
+    }
+
+    #[test]
+    fn assert_unit_interval_output() {
+        let abs: UnitInterval<f32> = unit!(-0.5f32).abs();
+        assert_eq!(abs, UnitInterval::new(0.5));
+        let sqrt: UnitInterval<f32> = unit!(-0.0f32).sqrt();
+        assert_eq!(sqrt, UnitInterval::new(0.0));
+        let square: UnitInterval<f32> = unit!(-0.5f32).square_powi(1);
+        assert_eq!(square, UnitInterval::new(0.25));
+        assert_err!(unit!(0.5f32).try_square_powi(-1));
+    }
+
+    #[test]
+    fn assert_trig() {
+        use std::f32::consts::{FRAC_1_SQRT_2, PI};
+
+        let finite = Finite::new;
+        assert_epsilon!(unit!(-1.0f32).asin(), finite(-PI / 2.0));
+        assert_epsilon!(unit!(FRAC_1_SQRT_2).asin(), finite(PI / 4.0));
+        assert_epsilon!(unit!(-1.0f32).acos(), finite(PI));
+        assert_epsilon!(unit!(1.0f32).acos(), finite(0.0));
+
+        let (s, c) = finite(PI / 2.0).sin_cos_unit();
+        assert_epsilon!(s, unit!(1.0));
+        assert_epsilon!(c, unit!(0.0));
+        assert_epsilon!(finite(PI).cos_unit(), unit!(-1.0));
+        assert_epsilon!(finite(PI / 4.0).sin_unit().asin(), finite(PI / 4.0));
+    }
+
+    #[test]
+    fn assert_convert() {
+        assert_eq!(Finite::from(unit!(-0.5f32)), Finite::new(-0.5));
+        assert_eq!(SignedUnit::from(UnitInterval::new(0.5f32)), unit!(0.5));
+    }
+}
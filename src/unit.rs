@@ -0,0 +1,75 @@
+//! Units of measure for a [`Quantity`](crate::Quantity).
+//!
+//! Units are uninhabited marker types. Multiplying or dividing two quantities produces
+//! a quantity with a derived unit, [`Prod`] or [`Per`]. Note that derived units are not simplified
+//! automatically, so `Prod<Meters, Seconds>` and `Prod<Seconds, Meters>` are different types.
+//! A quantity can be converted to an [`Equivalent`] unit with
+//! [`Quantity::into_unit`](crate::Quantity::into_unit), such as from `Prod<Per<Meters, Seconds>, Seconds>`
+//! back to `Meters`.
+//!
+//! You can define your own units by implementing [`Unit`].
+
+use std::{fmt, marker::PhantomData};
+
+/// A unit of measure for a [`Quantity`](crate::Quantity).
+pub trait Unit {
+    /// Writes the symbol for this unit, such as `m` or `m/s`.
+    /// # Errors
+    /// If the formatter returns an error.
+    fn fmt_symbol(f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+/// The unit of length, `m`.
+pub enum Meters {}
+impl Unit for Meters {
+    fn fmt_symbol(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "m")
+    }
+}
+
+/// The unit of time, `s`.
+pub enum Seconds {}
+impl Unit for Seconds {
+    fn fmt_symbol(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "s")
+    }
+}
+
+/// The unit of mass, `kg`.
+pub enum Kilograms {}
+impl Unit for Kilograms {
+    fn fmt_symbol(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "kg")
+    }
+}
+
+/// The unit produced by multiplying a quantity in `A` by a quantity in `B`.
+pub struct Prod<A, B>(PhantomData<(A, B)>);
+impl<A: Unit, B: Unit> Unit for Prod<A, B> {
+    fn fmt_symbol(f: &mut fmt::Formatter) -> fmt::Result {
+        A::fmt_symbol(f)?;
+        write!(f, "·")?;
+        B::fmt_symbol(f)
+    }
+}
+
+/// The unit produced by dividing a quantity in `A` by a quantity in `B`.
+pub struct Per<A, B>(PhantomData<(A, B)>);
+impl<A: Unit, B: Unit> Unit for Per<A, B> {
+    fn fmt_symbol(f: &mut fmt::Formatter) -> fmt::Result {
+        A::fmt_symbol(f)?;
+        write!(f, "/")?;
+        B::fmt_symbol(f)
+    }
+}
+
+/// Marker for a unit that is equivalent to the unit `U`,
+/// so that a quantity can be converted from one to the other.
+pub trait Equivalent<U: Unit>: Unit {}
+impl<U: Unit> Equivalent<U> for U {}
+// `(a/b)·b = a`
+impl<A: Unit, B: Unit> Equivalent<A> for Prod<Per<A, B>, B> {}
+// `b·(a/b) = a`
+impl<U: Unit, V: Unit> Equivalent<U> for Prod<V, Per<U, V>> {}
+// `(a·b)/b = a`
+impl<A: Unit, B: Unit> Equivalent<A> for Per<Prod<A, B>, B> {}
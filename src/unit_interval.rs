@@ -0,0 +1,146 @@
+use crate::{
+    constraint::UnitIntervalC, Checked, Finite, FiniteNonNeg, IsFinite, IsNan, IsNegative,
+    IsSignedUnit, NonNeg, Real, SignedUnit,
+};
+
+/// The error produced when a value outside of `[0, 1]` or NaN is encountered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitIntervalError;
+impl std::fmt::Display for UnitIntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "encountered a value outside of [0, 1] or NaN unexpectedly"
+        )
+    }
+}
+
+/// Trait for a floating point number that can be checked for being within the unit interval `[0, 1]`.
+pub trait IsUnitInterval: Sized + Copy {
+    /// Returns true if `0.0 <= self <= 1.0`. Negative zero is not considered to be in the unit interval.
+    fn is_unit_interval(self) -> bool;
+    /// Computes `1 - self`.
+    #[must_use]
+    fn complement(self) -> Self;
+}
+
+/// A floating point number in the unit interval `[0, 1]`,
+/// useful for probabilities, blend factors, and other normalized parameters.
+pub type UnitInterval<F> = Checked<F, UnitIntervalC>;
+
+impl<F: IsUnitInterval + Default> Default for UnitInterval<F> {
+    fn default() -> Self {
+        Self::new(F::default())
+    }
+}
+
+impl<F: IsUnitInterval> UnitInterval<F> {
+    /// Computes `1 - self`, which is always in the unit interval.
+    #[must_use]
+    pub fn complement(self) -> Self {
+        unsafe { Self::unchecked(self.val().complement()) }
+    }
+}
+
+round_impls!(UnitInterval<F: IsUnitInterval>);
+signed_impls!(UnitInterval<F: IsUnitInterval>);
+sum_impls!(
+    UnitInterval<F: IsUnitInterval>,
+    UnitIntervalError,
+    "If the result is outside of `[0, 1]` or NaN."
+);
+// neg is not defined
+
+closed_product_impls!(
+    UnitInterval<F: IsUnitInterval>,
+    UnitIntervalError,
+    "If the result is outside of `[0, 1]` or NaN."
+);
+
+impl<F: IsUnitInterval + crate::ops::Pow> UnitInterval<F> {
+    pow_methods!(
+        F,
+        UnitIntervalError,
+        "If the result is outside of `[0, 1]` or NaN."
+    );
+    sqrt_methods!(F);
+    cbrt_methods!(F);
+}
+
+convert_impls!(
+    UnitInterval<F: IsUnitInterval>,
+    UnitIntervalError => FiniteNonNeg<F: IsFinite + IsNegative>,
+    NonNeg<F: IsNegative>,
+    Finite<F: IsFinite>,
+    SignedUnit<F: IsSignedUnit>,
+    Real<F: IsNan>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! unit {
+        ($f: expr) => {
+            UnitInterval::new($f)
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "encountered a value outside of [0, 1] or NaN unexpectedly")]
+    fn assert_new_nan() {
+        unit!(f32::NAN);
+    }
+    #[test]
+    #[should_panic(expected = "encountered a value outside of [0, 1] or NaN unexpectedly")]
+    fn assert_new_neg() {
+        unit!(-0.0f32);
+    }
+    #[test]
+    #[should_panic(expected = "encountered a value outside of [0, 1] or NaN unexpectedly")]
+    fn assert_new_big() {
+        unit!(1.0f32 + f32::EPSILON);
+    }
+
+    #[test]
+    fn unchecked() {
+        let unit = unsafe { UnitInterval::unchecked(2.0f32) };
+        assert!(unit.val() > 1.0);
+    }
+
+    #[test]
+    fn assert_nan() {
+        assert_err!(unit!(0.5f32).try_add(0.75));
+        assert_err!(unit!(0.5f32).try_sub(0.75));
+        assert_err!(unit!(0.5f32).try_mul(3.0));
+        assert_err!(unit!(0.5f32).try_div(0.25));
+        assert_err!(unit!(0.0f32).try_div(0.0));
+        assert_err!(unit!(0.5f32).try_powi(-1));
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(unit!(0.5f32) + 0.25, unit!(0.75));
+        assert_eq!(unit!(0.5f32) - 0.25, unit!(0.25));
+        assert_eq!(unit!(0.5f32) * unit!(0.5), unit!(0.25));
+        assert_eq!(unit!(0.25f32).complement(), unit!(0.75));
+        assert_eq!(unit!(1.0f32).complement(), unit!(0.0));
+        assert_eq!(unit!(0.25f32).try_div(0.5).unwrap(), unit!(0.5));
+        assert_eq!(unit!(0.25f32).sqrt(), unit!(0.5));
+    }
+
+    #[test]
+    fn assert_convert() {
+        assert_eq!(NonNeg::from(unit!(0.5f32)), NonNeg::new(0.5));
+        assert_eq!(Finite::from(unit!(1.0f32)), Finite::new(1.0));
+        assert_eq!(FiniteNonNeg::from(unit!(0.5f32)), FiniteNonNeg::new(0.5));
+        assert_eq!(
+            UnitInterval::try_from(FiniteNonNeg::new(0.5f32)),
+            Ok(unit!(0.5))
+        );
+        assert_eq!(
+            UnitInterval::try_from(FiniteNonNeg::new(2.0f32)),
+            Err(UnitIntervalError)
+        );
+    }
+}
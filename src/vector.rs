@@ -0,0 +1,390 @@
+use crate::{
+    constraint::Constraint, ops::Pow, Checked, Finite, IntoInner, IsFinite, IsNegative, IsZero,
+    NonNeg, ToOrd,
+};
+
+/// The error produced when trying to normalize a vector with a length of zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroLengthError;
+impl std::fmt::Display for ZeroLengthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "attempted to normalize a vector with a length of zero")
+    }
+}
+
+/// A two-dimensional vector of checked floating point numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A three-dimensional vector of checked floating point numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// A vector with a length of one, such as [`Vec2`] or [`Vec3`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct UnitVec<V>(V);
+
+impl<V> UnitVec<V> {
+    /// Const-safe constructor for `UnitVec` that never checks the vector.
+    /// # Safety
+    /// Ensure that the vector has a length of one (give or take rounding errors).
+    pub const unsafe fn unchecked(val: V) -> Self {
+        Self(val)
+    }
+    /// Gets the inner vector.
+    #[inline]
+    pub fn get(self) -> V {
+        self.0
+    }
+}
+impl<V: ::core::ops::Neg<Output = V>> ::core::ops::Neg for UnitVec<V> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+// Each operation checks each component of the result, just like `sum_impls!` and `product_impls!`.
+macro_rules! vec_impls {
+    ($ty: ident { $first: ident $(, $c: ident)* }) => {
+        impl<T> $ty<T> {
+            /// Creates a new vector from its components.
+            pub const fn new($first: T $(, $c: T)*) -> Self {
+                Self { $first $(, $c)* }
+            }
+        }
+
+        impl<F: Copy, C: Constraint<F>> $ty<Checked<F, C>> {
+            /// Attempts to add two vectors.
+            /// # Errors
+            /// If any component of the result does not uphold the constraint `C`.
+            pub fn try_add(self, rhs: Self) -> Result<Self, C::Error>
+            where
+                F: ::core::ops::Add<Output = F>,
+            {
+                Ok(Self {
+                    $first: Checked::try_new(self.$first.val() + rhs.$first.val())?,
+                    $($c: Checked::try_new(self.$c.val() + rhs.$c.val())?,)*
+                })
+            }
+            /// Attempts to subtract two vectors.
+            /// # Errors
+            /// If any component of the result does not uphold the constraint `C`.
+            pub fn try_sub(self, rhs: Self) -> Result<Self, C::Error>
+            where
+                F: ::core::ops::Sub<Output = F>,
+            {
+                Ok(Self {
+                    $first: Checked::try_new(self.$first.val() - rhs.$first.val())?,
+                    $($c: Checked::try_new(self.$c.val() - rhs.$c.val())?,)*
+                })
+            }
+            /// Attempts to multiply a vector by a scalar.
+            /// # Errors
+            /// If any component of the result does not uphold the constraint `C`.
+            pub fn try_mul(self, rhs: impl IntoInner<F>) -> Result<Self, C::Error>
+            where
+                F: ::core::ops::Mul<Output = F>,
+            {
+                let rhs = rhs.into_inner();
+                Ok(Self {
+                    $first: Checked::try_new(self.$first.val() * rhs)?,
+                    $($c: Checked::try_new(self.$c.val() * rhs)?,)*
+                })
+            }
+            /// Attempts to divide a vector by a scalar.
+            /// # Errors
+            /// If any component of the result does not uphold the constraint `C`.
+            pub fn try_div(self, rhs: impl IntoInner<F>) -> Result<Self, C::Error>
+            where
+                F: ::core::ops::Div<Output = F>,
+            {
+                let rhs = rhs.into_inner();
+                Ok(Self {
+                    $first: Checked::try_new(self.$first.val() / rhs)?,
+                    $($c: Checked::try_new(self.$c.val() / rhs)?,)*
+                })
+            }
+            /// Attempts to negate a vector.
+            /// # Errors
+            /// If any component of the result does not uphold the constraint `C`.
+            pub fn try_neg(self) -> Result<Self, C::Error>
+            where
+                F: ::core::ops::Neg<Output = F>,
+            {
+                Ok(Self {
+                    $first: Checked::try_new(-self.$first.val())?,
+                    $($c: Checked::try_new(-self.$c.val())?,)*
+                })
+            }
+
+            /// Attempts to compute the dot product of two vectors.
+            /// # Errors
+            /// If the result does not uphold the constraint `C`.
+            pub fn try_dot(self, rhs: Self) -> Result<Checked<F, C>, C::Error>
+            where
+                F: ::core::ops::Add<Output = F> + ::core::ops::Mul<Output = F>,
+            {
+                Checked::try_new(self.$first.val() * rhs.$first.val() $(+ self.$c.val() * rhs.$c.val())*)
+            }
+            /// Computes the dot product of two vectors.
+            /// # Panics
+            /// If the result does not uphold the constraint `C`.
+            #[track_caller]
+            #[must_use]
+            pub fn dot(self, rhs: Self) -> Checked<F, C>
+            where
+                F: ::core::ops::Add<Output = F> + ::core::ops::Mul<Output = F>,
+            {
+                Checked::new(self.$first.val() * rhs.$first.val() $(+ self.$c.val() * rhs.$c.val())*)
+            }
+        }
+
+        impl<F: Copy, C: Constraint<F>> ::core::ops::Add for $ty<Checked<F, C>>
+        where
+            F: ::core::ops::Add<Output = F>,
+        {
+            type Output = Self;
+            #[track_caller]
+            fn add(self, rhs: Self) -> Self {
+                Self {
+                    $first: Checked::new(self.$first.val() + rhs.$first.val()),
+                    $($c: Checked::new(self.$c.val() + rhs.$c.val()),)*
+                }
+            }
+        }
+        impl<F: Copy, C: Constraint<F>> ::core::ops::Sub for $ty<Checked<F, C>>
+        where
+            F: ::core::ops::Sub<Output = F>,
+        {
+            type Output = Self;
+            #[track_caller]
+            fn sub(self, rhs: Self) -> Self {
+                Self {
+                    $first: Checked::new(self.$first.val() - rhs.$first.val()),
+                    $($c: Checked::new(self.$c.val() - rhs.$c.val()),)*
+                }
+            }
+        }
+        impl<F: Copy, C: Constraint<F>, Rhs: IntoInner<F>> ::core::ops::Mul<Rhs> for $ty<Checked<F, C>>
+        where
+            F: ::core::ops::Mul<Output = F>,
+        {
+            type Output = Self;
+            #[track_caller]
+            fn mul(self, rhs: Rhs) -> Self {
+                let rhs = rhs.into_inner();
+                Self {
+                    $first: Checked::new(self.$first.val() * rhs),
+                    $($c: Checked::new(self.$c.val() * rhs),)*
+                }
+            }
+        }
+        impl<F: Copy, C: Constraint<F>, Rhs: IntoInner<F>> ::core::ops::Div<Rhs> for $ty<Checked<F, C>>
+        where
+            F: ::core::ops::Div<Output = F>,
+        {
+            type Output = Self;
+            #[track_caller]
+            fn div(self, rhs: Rhs) -> Self {
+                let rhs = rhs.into_inner();
+                Self {
+                    $first: Checked::new(self.$first.val() / rhs),
+                    $($c: Checked::new(self.$c.val() / rhs),)*
+                }
+            }
+        }
+        impl<F: Copy, C: Constraint<F>> ::core::ops::Neg for $ty<Checked<F, C>>
+        where
+            F: ::core::ops::Neg<Output = F>,
+        {
+            type Output = Self;
+            #[track_caller]
+            fn neg(self) -> Self {
+                Self {
+                    $first: Checked::new(-self.$first.val()),
+                    $($c: Checked::new(-self.$c.val()),)*
+                }
+            }
+        }
+
+        impl<F: IsNegative + Pow, C: Constraint<F>> $ty<Checked<F, C>> {
+            /// Computes the length of this vector.
+            ///
+            /// This will be infinite if the length is too large to be represented.
+            #[must_use]
+            pub fn length(self) -> NonNeg<F> {
+                NonNeg::new(self.$first.val()$(.hypot(self.$c.val()))*)
+            }
+        }
+
+        impl<F> $ty<Finite<F>>
+        where
+            F: IsFinite + IsZero + Pow + IsNegative + ToOrd + ::core::ops::Div<Output = F>,
+        {
+            /// Attempts to scale this vector to have a length of one.
+            /// # Errors
+            /// If the vector has a length of zero.
+            pub fn try_normalize(self) -> Result<UnitVec<Self>, ZeroLengthError> {
+                // Scale the vector down first, so that finding the length never overflows.
+                let max = self.$first.abs()$(.max(self.$c.abs()))*.val();
+                if max.is_zero() {
+                    return Err(ZeroLengthError);
+                }
+                let $first = self.$first.val() / max;
+                $(let $c = self.$c.val() / max;)*
+                let len = $first$(.hypot($c))*;
+                // every component of the result is within `[-1, 1]`, so it's finite.
+                unsafe {
+                    Ok(UnitVec(Self {
+                        $first: Finite::unchecked($first / len),
+                        $($c: Finite::unchecked($c / len),)*
+                    }))
+                }
+            }
+            /// Scales this vector to have a length of one.
+            /// # Panics
+            /// If the vector has a length of zero.
+            #[track_caller]
+            #[must_use]
+            pub fn normalize(self) -> UnitVec<Self> {
+                crate::unwrap_display(self.try_normalize())
+            }
+        }
+    };
+}
+
+vec_impls!(Vec2 { x, y });
+vec_impls!(Vec3 { x, y, z });
+
+impl<F: Copy, C: Constraint<F>> Vec2<Checked<F, C>> {
+    /// Attempts to compute the 2D cross product (the z component of the 3D cross product).
+    /// # Errors
+    /// If the result does not uphold the constraint `C`.
+    pub fn try_cross(self, rhs: Self) -> Result<Checked<F, C>, C::Error>
+    where
+        F: ::core::ops::Sub<Output = F> + ::core::ops::Mul<Output = F>,
+    {
+        Checked::try_new(self.x.val() * rhs.y.val() - self.y.val() * rhs.x.val())
+    }
+    /// Computes the 2D cross product (the z component of the 3D cross product).
+    /// # Panics
+    /// If the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> Checked<F, C>
+    where
+        F: ::core::ops::Sub<Output = F> + ::core::ops::Mul<Output = F>,
+    {
+        Checked::new(self.x.val() * rhs.y.val() - self.y.val() * rhs.x.val())
+    }
+}
+impl<F: Copy, C: Constraint<F>> Vec3<Checked<F, C>> {
+    /// Attempts to compute the cross product of two vectors.
+    /// # Errors
+    /// If any component of the result does not uphold the constraint `C`.
+    pub fn try_cross(self, rhs: Self) -> Result<Self, C::Error>
+    where
+        F: ::core::ops::Sub<Output = F> + ::core::ops::Mul<Output = F>,
+    {
+        let (a, b) = (self, rhs);
+        Ok(Self {
+            x: Checked::try_new(a.y.val() * b.z.val() - a.z.val() * b.y.val())?,
+            y: Checked::try_new(a.z.val() * b.x.val() - a.x.val() * b.z.val())?,
+            z: Checked::try_new(a.x.val() * b.y.val() - a.y.val() * b.x.val())?,
+        })
+    }
+    /// Computes the cross product of two vectors.
+    /// # Panics
+    /// If any component of the result does not uphold the constraint `C`.
+    #[track_caller]
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> Self
+    where
+        F: ::core::ops::Sub<Output = F> + ::core::ops::Mul<Output = F>,
+    {
+        crate::unwrap_display(self.try_cross(rhs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! v2 {
+        ($x: expr, $y: expr) => {
+            Vec2::new(Finite::new($x), Finite::new($y))
+        };
+    }
+    macro_rules! v3 {
+        ($x: expr, $y: expr, $z: expr) => {
+            Vec3::new(Finite::new($x), Finite::new($y), Finite::new($z))
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to normalize a vector with a length of zero")]
+    fn assert_normalize_zero() {
+        let _ = v2!(0.0f32, -0.0).normalize();
+    }
+
+    #[test]
+    fn assert_nan() {
+        assert_err!(v2!(f32::MAX, 0.0).try_add(v2!(f32::MAX, 0.0)));
+        assert_err!(v2!(0.0, -f32::MAX).try_sub(v2!(0.0, f32::MAX)));
+        assert_err!(v3!(1.0, 2.0, f32::MAX).try_mul(2.0));
+        assert_err!(v3!(1.0f32, 2.0, 3.0).try_div(0.0));
+        assert_err!(v2!(f32::MAX, f32::MAX).try_dot(v2!(f32::MAX, f32::MAX)));
+        assert_eq!(v2!(0.0f32, 0.0).try_normalize(), Err(ZeroLengthError));
+
+        let nn = Vec2::new(NonNeg::new(1.0f32), NonNeg::new(2.0));
+        assert_err!(nn.try_neg());
+        assert_err!(nn.try_sub(Vec2::new(NonNeg::new(2.0), NonNeg::new(1.0))));
+    }
+
+    #[test]
+    fn assert_ops() {
+        assert_eq!(v2!(1.0f32, 2.0) + v2!(3.0, 4.0), v2!(4.0, 6.0));
+        assert_eq!(v2!(1.0f32, 2.0) - v2!(3.0, 4.0), v2!(-2.0, -2.0));
+        assert_eq!(v3!(1.0f32, 2.0, 3.0) * 2.0, v3!(2.0, 4.0, 6.0));
+        assert_eq!(v3!(2.0f32, 4.0, 6.0) / 2.0, v3!(1.0, 2.0, 3.0));
+        assert_eq!(-v3!(1.0f32, -2.0, 3.0), v3!(-1.0, 2.0, -3.0));
+
+        assert_eq!(v2!(1.0f32, 2.0).dot(v2!(3.0, 4.0)), Finite::new(11.0));
+        assert_eq!(v2!(1.0f32, 0.0).cross(v2!(0.0, 1.0)), Finite::new(1.0));
+        assert_eq!(
+            v3!(1.0f32, 0.0, 0.0).cross(v3!(0.0, 1.0, 0.0)),
+            v3!(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn assert_length() {
+        assert_eq!(v2!(3.0f32, 4.0).length(), NonNeg::new(5.0));
+        assert_eq!(v3!(2.0f32, 3.0, 6.0).length(), NonNeg::new(7.0));
+        assert_eq!(v2!(f32::MAX, f32::MAX).length(), NonNeg::new(f32::INFINITY));
+    }
+
+    #[test]
+    fn assert_normalize() {
+        assert_eq!(v2!(3.0f32, 4.0).normalize().get(), v2!(0.6, 0.8));
+        assert_eq!(
+            v3!(0.0f32, -2.0, 0.0).normalize().get(),
+            v3!(0.0, -1.0, 0.0)
+        );
+        assert_eq!((-v2!(0.0f32, 5.0).normalize()).get(), v2!(0.0, -1.0));
+
+        // the length of this vector overflows, but it can still be normalized.
+        let unit = v2!(f32::MAX, f32::MAX).normalize().get();
+        assert_epsilon!(unit.x, Finite::new(std::f32::consts::FRAC_1_SQRT_2));
+        assert_epsilon!(unit.y, Finite::new(std::f32::consts::FRAC_1_SQRT_2));
+    }
+}
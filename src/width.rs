@@ -0,0 +1,142 @@
+use crate::{
+    constraint::{
+        And, Constraint, FiniteC, LogProbabilityC, NegativeC, NonNegC, NonPosC, NonZeroC, NormalC,
+        PositiveC, RealC, SignedUnitC, UnitIntervalC,
+    },
+    Checked,
+};
+
+/// The error produced when narrowing an `f64` checked float to `f32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NarrowError<E> {
+    /// The value is finite, but too large in magnitude to be represented as an `f32`.
+    Overflow,
+    /// The value had to be rounded, which is only checked by `try_narrow_exact`.
+    Inexact,
+    /// The rounded value does not uphold the constraint, such as when a `Positive` underflows to zero.
+    Constraint(E),
+}
+impl<E: std::fmt::Display> std::fmt::Display for NarrowError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "value overflowed when narrowing to `f32`"),
+            Self::Inexact => write!(f, "value was rounded when narrowing to `f32`"),
+            Self::Constraint(e) => e.fmt(f),
+        }
+    }
+}
+
+// These are only implemented for the built-in constraints, since a user-defined
+// constraint could accept an `f32` value without accepting the same `f64` value.
+macro_rules! width_impls {
+    ($($c: ty),*) => {$(
+        impl From<Checked<f32, $c>> for Checked<f64, $c> {
+            #[inline]
+            fn from(val: Checked<f32, $c>) -> Self {
+                // widening is exact, so the value still upholds the constraint.
+                unsafe { Self::unchecked(f64::from(val.val())) }
+            }
+        }
+        impl TryFrom<Checked<f64, $c>> for Checked<f32, $c> {
+            type Error = NarrowError<<$c as Constraint<f32>>::Error>;
+            #[allow(clippy::cast_possible_truncation)]
+            fn try_from(val: Checked<f64, $c>) -> Result<Self, Self::Error> {
+                let wide = val.val();
+                let narrow = wide as f32;
+                if narrow.is_infinite() && wide.is_finite() {
+                    return Err(NarrowError::Overflow);
+                }
+                Self::try_new(narrow).map_err(NarrowError::Constraint)
+            }
+        }
+        impl Checked<f64, $c> {
+            /// Attempts to convert this to an `f32`, without any rounding.
+            /// # Errors
+            /// If the value overflows, needs to be rounded, or no longer upholds the constraint.
+            pub fn try_narrow_exact(
+                self,
+            ) -> Result<Checked<f32, $c>, NarrowError<<$c as Constraint<f32>>::Error>> {
+                let narrow = Checked::<f32, $c>::try_from(self)?;
+                if f64::from(narrow.val()).to_bits() == self.val().to_bits() {
+                    Ok(narrow)
+                } else {
+                    Err(NarrowError::Inexact)
+                }
+            }
+        }
+    )*};
+}
+width_impls!(
+    RealC,
+    FiniteC,
+    NonNegC,
+    NonPosC,
+    PositiveC,
+    NegativeC,
+    NonZeroC,
+    UnitIntervalC,
+    SignedUnitC,
+    NormalC,
+    LogProbabilityC,
+    And<FiniteC, NonNegC>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Finite, Normal, NormalError, Positive, PositiveError, Real};
+
+    #[test]
+    fn assert_widen() {
+        assert_eq!(
+            Real::<f64>::from(Real::new(0.1f32)),
+            Real::new(f64::from(0.1f32))
+        );
+        assert_eq!(
+            Finite::<f64>::from(Finite::new(f32::MAX)),
+            Finite::new(f64::from(f32::MAX))
+        );
+        assert_eq!(
+            Real::<f64>::from(Real::new(f32::INFINITY)),
+            Real::new(f64::INFINITY)
+        );
+    }
+
+    #[test]
+    fn assert_narrow() {
+        assert_eq!(Real::<f32>::try_from(Real::new(0.1f64)), Ok(Real::new(0.1)));
+        assert_eq!(
+            Real::<f32>::try_from(Real::new(1e300f64)),
+            Err(NarrowError::Overflow)
+        );
+        assert_eq!(
+            Real::<f32>::try_from(Real::new(f64::INFINITY)),
+            Ok(Real::new(f32::INFINITY))
+        );
+        assert_eq!(
+            Finite::<f32>::try_from(Finite::new(-1e300f64)),
+            Err(NarrowError::Overflow)
+        );
+        assert_eq!(
+            Positive::<f32>::try_from(Positive::new(1e-300f64)),
+            Err(NarrowError::Constraint(PositiveError))
+        );
+        assert_eq!(
+            Normal::<f32>::try_from(Normal::new(1e-40f64)),
+            Err(NarrowError::Constraint(NormalError::Subnormal))
+        );
+    }
+
+    #[test]
+    fn assert_narrow_exact() {
+        assert_eq!(Real::new(0.5f64).try_narrow_exact(), Ok(Real::new(0.5f32)));
+        assert_eq!(
+            Real::new(0.1f64).try_narrow_exact(),
+            Err::<Real<f32>, _>(NarrowError::Inexact)
+        );
+        assert_eq!(
+            Finite::new(1e300f64).try_narrow_exact(),
+            Err::<Finite<f32>, _>(NarrowError::Overflow)
+        );
+    }
+}